@@ -0,0 +1,308 @@
+//! State-root builder shared by the `trading_competition` program and the
+//! operator's off-chain tooling.
+//!
+//! The program only ever verifies proofs; the operator builds the tree from
+//! the final ER state, passes [`StateTree::root`] to `final_commit` and hands
+//! each trader [`StateTree::proof_for`]. The same tree over
+//! [`allowlist_leaf`]s gives the registration allowlist root. Both sides hash through this crate,
+//! so the two can't drift apart. No Anchor dependency – sha256 goes through
+//! the syscall on-chain and `sha2` everywhere else.
+
+use solana_sha256_hasher::hashv;
+
+/// Domain separators so a leaf can never be replayed as an inner node.
+pub const LEAF_PREFIX: &[u8] = &[0x00];
+pub const NODE_PREFIX: &[u8] = &[0x01];
+pub const ALLOWLIST_PREFIX: &[u8] = &[0x02];
+
+/// Canonical leaf of a Position committed under `Competition.state_root`:
+/// sha256(0x00 || user || usdc_balance || initial_value || current_value ||
+/// profit || trade_count || total_volume || active_days || last_trade_at ||
+/// max_drawdown || sample_count || return_sum || return_sq_sum), all integers
+/// little-endian. Covers every input to scoring, tie-breaks and eligibility,
+/// so a proven leaf is enough to re-rank the position.
+pub fn position_leaf(p: &PositionLeaf) -> [u8; 32] {
+    hashv(&[
+        LEAF_PREFIX,
+        &p.user,
+        &p.usdc_balance.to_le_bytes(),
+        &p.initial_value.to_le_bytes(),
+        &p.current_value.to_le_bytes(),
+        &p.profit().to_le_bytes(),
+        &p.trade_count.to_le_bytes(),
+        &p.total_volume.to_le_bytes(),
+        &p.active_days.to_le_bytes(),
+        &p.last_trade_at.to_le_bytes(),
+        &p.max_drawdown.to_le_bytes(),
+        &p.sample_count.to_le_bytes(),
+        &p.return_sum.to_le_bytes(),
+        &p.return_sq_sum.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Leaf of a wallet allowed to register: sha256(0x02 || wallet).
+pub fn allowlist_leaf(wallet: &[u8; 32]) -> [u8; 32] {
+    hashv(&[ALLOWLIST_PREFIX, wallet]).to_bytes()
+}
+
+/// Inner node – children are sorted so proofs don't need direction bits.
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, lo, hi]).to_bytes()
+}
+
+pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |acc, sibling| hash_pair(&acc, sibling));
+    computed == *root
+}
+
+/// The Position fields that go into a leaf.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PositionLeaf {
+    pub user: [u8; 32],
+    pub usdc_balance: u128,
+    pub initial_value: u128,
+    pub current_value: u128,
+    pub trade_count: u32,
+    pub total_volume: u128,
+    pub active_days: u16,
+    pub last_trade_at: i64,
+    pub max_drawdown: u128,
+    pub sample_count: u32,
+    pub return_sum: i128,
+    pub return_sq_sum: u128,
+}
+
+impl PositionLeaf {
+    /// Same derivation as `Position::profit` on-chain.
+    pub fn profit(&self) -> i128 {
+        self.current_value as i128 - self.initial_value as i128
+    }
+
+    pub fn hash(&self) -> [u8; 32] {
+        position_leaf(self)
+    }
+}
+
+/// Two leaves were given for the same key; its proof would be ambiguous.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DuplicateKey(pub [u8; 32]);
+
+impl std::fmt::Display for DuplicateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "duplicate leaf key ")?;
+        self.0.iter().try_for_each(|b| write!(f, "{:02x}", b))
+    }
+}
+
+impl std::error::Error for DuplicateKey {}
+
+/// Merkle tree over position (or allowlist) leaves.
+///
+/// Leaves are ordered by user key so any two builders of the same state end
+/// up with the same root. An odd node at the end of a level is carried up
+/// unchanged. Each key may appear only once.
+#[derive(Clone, Debug)]
+pub struct StateTree {
+    users: Vec<[u8; 32]>,
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl StateTree {
+    pub fn new(positions: &[PositionLeaf]) -> Result<Self, DuplicateKey> {
+        Self::from_keyed(positions.iter().map(|p| (p.user, p.hash())).collect())
+    }
+
+    /// Tree whose root is `Competition.allowlist_root`.
+    pub fn allowlist(wallets: &[[u8; 32]]) -> Result<Self, DuplicateKey> {
+        Self::from_keyed(wallets.iter().map(|w| (*w, allowlist_leaf(w))).collect())
+    }
+
+    fn from_keyed(mut leaves: Vec<([u8; 32], [u8; 32])>) -> Result<Self, DuplicateKey> {
+        leaves.sort_by_key(|(user, _)| *user);
+        if let Some(dup) = leaves.windows(2).find(|w| w[0].0 == w[1].0) {
+            return Err(DuplicateKey(dup[0].0));
+        }
+
+        let users = leaves.iter().map(|(user, _)| *user).collect();
+        let mut levels = vec![leaves.into_iter().map(|(_, leaf)| leaf).collect::<Vec<_>>()];
+        while levels.last().is_some_and(|l| l.len() > 1) {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => hash_pair(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        Ok(Self { users, levels })
+    }
+
+    /// Root to pass to `final_commit` / `set_registration`; all zeroes for an
+    /// empty tree.
+    pub fn root(&self) -> [u8; 32] {
+        self.levels
+            .last()
+            .and_then(|l| l.first())
+            .copied()
+            .unwrap_or([0u8; 32])
+    }
+
+    /// Proof for `user`'s leaf, or `None` if the user isn't in the tree.
+    pub fn proof_for(&self, user: &[u8; 32]) -> Option<Vec<[u8; 32]>> {
+        let mut index = self.users.binary_search(user).ok()?;
+        let mut proof = Vec::new();
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = index ^ 1;
+            if sibling < level.len() {
+                proof.push(level[sibling]);
+            }
+            index /= 2;
+        }
+        Some(proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(i: u8) -> PositionLeaf {
+        PositionLeaf {
+            user: [i; 32],
+            usdc_balance: 1_000 * i as u128,
+            initial_value: 1_000_000,
+            current_value: 1_000_000 + i as u128 * 500 - 1_000,
+            trade_count: 3 * i as u32,
+            total_volume: 250_000 * i as u128,
+            active_days: i as u16,
+            last_trade_at: 1_700_000_000 + i as i64,
+            max_drawdown: 40 * i as u128,
+            sample_count: 2 * i as u32,
+            return_sum: -(i as i128),
+            return_sq_sum: 9 * i as u128,
+        }
+    }
+
+    fn positions(n: u8) -> Vec<PositionLeaf> {
+        (1..=n).map(position).collect()
+    }
+
+    #[test]
+    fn empty_tree_has_zero_root_and_no_proofs() {
+        let tree = StateTree::new(&[]).unwrap();
+        assert_eq!(tree.root(), [0u8; 32]);
+        assert_eq!(tree.proof_for(&[1; 32]), None);
+    }
+
+    #[test]
+    fn single_leaf_is_the_root() {
+        let p = position(1);
+        let tree = StateTree::new(&[p]).unwrap();
+        assert_eq!(tree.root(), p.hash());
+        assert_eq!(tree.proof_for(&p.user), Some(vec![]));
+        assert!(verify_proof(&[], &tree.root(), p.hash()));
+    }
+
+    #[test]
+    fn every_proof_round_trips() {
+        for n in 1..=9 {
+            let ps = positions(n);
+            let tree = StateTree::new(&ps).unwrap();
+            for p in &ps {
+                let proof = tree.proof_for(&p.user).unwrap();
+                assert!(verify_proof(&proof, &tree.root(), p.hash()), "n={n} user={}", p.user[0]);
+            }
+        }
+    }
+
+    #[test]
+    fn proof_rejects_altered_leaf() {
+        let ps = positions(4);
+        let tree = StateTree::new(&ps).unwrap();
+        let proof = tree.proof_for(&ps[1].user).unwrap();
+        let p = ps[1];
+        for forged in [
+            PositionLeaf { current_value: p.current_value + 1, ..p },
+            PositionLeaf { trade_count: p.trade_count - 1, ..p },
+            PositionLeaf { last_trade_at: p.last_trade_at - 1, ..p },
+            PositionLeaf { max_drawdown: 0, ..p },
+            PositionLeaf { sample_count: p.sample_count + 1, ..p },
+            PositionLeaf { return_sum: 0, ..p },
+            PositionLeaf { return_sq_sum: 0, ..p },
+            PositionLeaf { total_volume: 0, ..p },
+            PositionLeaf { active_days: 0, ..p },
+        ] {
+            assert!(!verify_proof(&proof, &tree.root(), forged.hash()), "{forged:?}");
+        }
+    }
+
+    #[test]
+    fn odd_leaf_is_carried_up() {
+        let ps = positions(3);
+        let tree = StateTree::new(&ps).unwrap();
+        let left = hash_pair(&ps[0].hash(), &ps[1].hash());
+        assert_eq!(tree.root(), hash_pair(&left, &ps[2].hash()));
+        assert_eq!(tree.proof_for(&ps[2].user), Some(vec![left]));
+    }
+
+    #[test]
+    fn root_ignores_input_order() {
+        let mut ps = positions(5);
+        let root = StateTree::new(&ps).unwrap().root();
+        ps.reverse();
+        assert_eq!(StateTree::new(&ps).unwrap().root(), root);
+    }
+
+    #[test]
+    fn duplicate_keys_are_rejected() {
+        let mut ps = positions(3);
+        ps.push(PositionLeaf { usdc_balance: 7, ..ps[1] });
+        assert_eq!(StateTree::new(&ps).unwrap_err(), DuplicateKey([2; 32]));
+        assert_eq!(
+            StateTree::allowlist(&[[1; 32], [1; 32]]).unwrap_err(),
+            DuplicateKey([1; 32])
+        );
+    }
+
+    #[test]
+    fn position_and_allowlist_leaves_are_domain_separated() {
+        let wallet = [9; 32];
+        let allow = StateTree::allowlist(&[wallet]).unwrap();
+        let pos = PositionLeaf { user: wallet, ..Default::default() };
+        assert_ne!(pos.hash(), allowlist_leaf(&wallet));
+        assert!(!verify_proof(&[], &allow.root(), pos.hash()));
+        // Nor can two leaves be passed off as an inner node
+        let ps = positions(2);
+        let node = hash_pair(&ps[0].hash(), &ps[1].hash());
+        assert_ne!(node, ps[0].hash());
+        assert_ne!(node, allowlist_leaf(&ps[0].user));
+    }
+
+    #[test]
+    fn known_answer_vectors() {
+        // sha256 over the documented encodings, computed independently
+        assert_eq!(
+            StateTree::new(&positions(3)).unwrap().root(),
+            hex("79a2b60615400b88fa14b8f67bfe73c8c42d0f1456f983df287401b0c22ade5c")
+        );
+        assert_eq!(
+            allowlist_leaf(&[7; 32]),
+            hex("81560c27f0dc97a367df31693f7ebd616e9d675b2c4a787454b5f41ae0488be6")
+        );
+    }
+
+    fn hex(s: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for (i, b) in out.iter_mut().enumerate() {
+            *b = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+}
@@ -1,8 +1,74 @@
 use anchor_lang::prelude::*;
+use std::cmp::Ordering;
+
+/// Lamports a challenger escrows with `challenge_winner`; forfeited to the
+/// treasurer if `resolve_challenge` rejects the challenge.
+pub const CHALLENGE_BOND_LAMPORTS: u64 = 100_000_000; // 0.1 SOL
+
+/// How long after `end_time` positions can be submitted in on-chain finalization.
+pub const SUBMISSION_WINDOW: i64 = 3600;
+
+/// How long after settlement the moderator can overturn results. Prizes,
+/// season points and ratings wait for it, so nothing paid out can move.
+pub const REVIEW_WINDOW: i64 = 86_400;
+
+/// Seconds between equity samples feeding the Sharpe-like score.
+pub const EQUITY_SAMPLE_INTERVAL: i64 = 300;
+
+/// Smallest return stddev (bps) the Sharpe score divides by, so a handful
+/// of identical returns can't outrank every real track record.
+pub const SHARPE_MIN_STDDEV_BPS: u128 = 100;
+
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Hard cap on `config.max_team_size`.
+pub const MAX_TEAM_SIZE: usize = 8;
+pub const TEAM_LEADERBOARD_SIZE: usize = 5;
+pub const MAX_TEAM_NAME_LEN: usize = 32;
+
+/// Ranked entries kept on `Competition`; also the cap on co-winners.
+pub const LEADERBOARD_SIZE: usize = 10;
+
+/// Rating every wallet starts from; Elo K-factor and floor.
+pub const INITIAL_RATING: u32 = 1500;
+pub const RATING_K: i64 = 32;
+pub const MIN_RATING: u32 = 100;
+/// Elo expected score in bps for a rating gap of 0, 25, .., 800 points
+/// (`10_000 / (1 + 10^(gap / 400))`); gaps past 800 clamp to the last entry.
+pub const EXPECTED_SCORE_BPS: [i64; 33] = [
+    5000, 4641, 4285, 3937, 3599, 3275, 2966, 2675, 2403, 2150, 1917, 1704, 1510, 1334, 1177,
+    1035, 909, 797, 698, 610, 532, 464, 405, 352, 307, 267, 232, 201, 175, 152, 132, 114, 99,
+];
+pub const EXPECTED_SCORE_STEP: i64 = 25;
+
+/// Achievement bits on `Position.achievements`; bit index = badge id.
+pub const ACHIEVEMENT_FIRST_TRADE: u8 = 1 << 0;
+pub const ACHIEVEMENT_PROFIT_STREAK: u8 = 1 << 1;   // PROFIT_STREAK_LEN profitable trades in a row
+pub const ACHIEVEMENT_DOUBLED: u8 = 1 << 2;         // current_value reached 2x initial_value
+pub const ACHIEVEMENT_TOP_DECILE: u8 = 1 << 3;      // finished in the top 10% of the field
+pub const ACHIEVEMENT_COUNT: u8 = 4;
+/// Badge names by achievement id.
+pub const ACHIEVEMENT_NAMES: [&str; ACHIEVEMENT_COUNT as usize] = [
+    "Cypherpunk First Trade",
+    "Cypherpunk Profit Streak",
+    "Cypherpunk Doubled",
+    "Cypherpunk Top Decile",
+];
+pub const ACHIEVEMENT_SYMBOL: &str = "CYBDG";
+pub const PROFIT_STREAK_LEN: u16 = 10;
+
+pub const MAX_HANDLE_LEN: usize = 32;
+
+/// Stored `uri_template` cap, and the rendered URI cap (Metaplex `MAX_URI_LENGTH`).
+pub const MAX_URI_TEMPLATE_LEN: usize = 128;
+pub const MAX_URI_LEN: usize = 200;
+/// Results kept in `TraderProfile.recent` (oldest overwritten first).
+pub const RECENT_RESULTS: usize = 5;
 
 /// Phase of a competition – replaces the old `is_active` bool.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
 pub enum CompetitionPhase {
+    #[default]
     Upcoming,
     Active,
     Finalizing,   // after final_commit, challenge window open
@@ -14,8 +80,175 @@ impl anchor_lang::Space for CompetitionPhase {
     const INIT_SPACE: usize = 1;  // u8 discriminant size
 }
 
+/// Who decides the winner.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FinalizationMode {
+    #[default]
+    Authority,    // authority posts state_root + winner via final_commit
+    OnChain,      // participants submit positions, program derives winner
+}
+
+impl anchor_lang::Space for FinalizationMode {
+    const INIT_SPACE: usize = 1;
+}
+
+/// How equal profits are ordered. Whatever the policy, remaining ties fall
+/// back to pubkey order so the board never depends on submission order.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreak {
+    #[default]
+    CoWinners,        // everyone tied at the top wins and splits the prize
+    EarliestToProfit, // earlier last trade (reached the final profit first)
+    FewestTrades,
+}
+
+impl anchor_lang::Space for TieBreak {
+    const INIT_SPACE: usize = 1;
+}
+
+/// What a winner is ranked by – see `Position::score`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScoringMode {
+    #[default]
+    AbsolutePnl,        // raw profit (6-dec USDC)
+    Roi,                // profit / initial_value, in bps
+    Sharpe,             // mean / stddev of sampled returns, x10_000
+    DrawdownPenalized,  // profit - max_drawdown * drawdown_penalty_bps / 10_000
+}
+
+impl anchor_lang::Space for ScoringMode {
+    const INIT_SPACE: usize = 1;
+}
+
+/// Rules fixed at `init_competition`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct CompetitionConfig {
+    pub finalization: FinalizationMode,
+    pub tie_break: TieBreak,
+    pub scoring: ScoringMode,
+    pub drawdown_penalty_bps: u16,  // DrawdownPenalized only; 10_000 = 1x
+    pub eligibility: EligibilityRules,
+    pub max_participants: u32,      // 0 = unlimited
+    pub entry_gate: EntryGate,
+    pub max_team_size: u8,          // 0 = no teams; at most MAX_TEAM_SIZE
+    pub trophy: TrophyKind,
+}
+
+/// Which trophy a podium finish earns; the other mint path stays closed.
+/// Soulbound is the default since only it carries the rank, profit and SVG
+/// on-chain; the collection NFT holds just a URI.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrophyKind {
+    #[default]
+    Soulbound,    // NonTransferable Token-2022 mint with in-mint metadata
+    Collection,   // transferable Metaplex NFT verified into the trophy collection
+}
+
+impl anchor_lang::Space for TrophyKind {
+    const INIT_SPACE: usize = 1;
+}
+
+/// Holding required to register.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EntryGate {
+    #[default]
+    Open,
+    TokenBalance { mint: Pubkey, min_amount: u64 },
+    Collection { collection: Pubkey },  // NFT from a verified Metaplex collection
+}
+
+impl anchor_lang::Space for EntryGate {
+    const INIT_SPACE: usize = 1 + 32 + 8;  // largest variant
+}
+
+/// Minimum activity to be ranked, crowned, minted or paid. All zero = open.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct EligibilityRules {
+    pub min_trades: u32,
+    pub min_volume: u128,           // notional (6-dec USDC)
+    pub min_active_days: u16,       // distinct UTC days with a trade
+}
+
+/// Admin role a key can be granted with `set_role`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    PriceKeeper,        // update_mock_price
+    Finalizer,          // final_commit, settle_competition
+    Treasurer,          // sponsored mints paid by the competition side, forfeited bonds
+    Moderator,          // start, registration, URI template, overturns, trophy corrections
+}
+
+/// Holders of each admin role; all start as `authority`.
+///
+/// Deliberately left permissionless, since each one only applies rules
+/// already fixed on-chain: finalize_winner, tally_team_member,
+/// user_undelegate, resolve_challenge, update_rating and award_season_points.
+/// fund_prize is open too – it can only add to the vault.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct Roles {
+    pub price_keeper: Pubkey,
+    pub finalizer: Pubkey,
+    pub treasurer: Pubkey,
+    pub moderator: Pubkey,
+}
+
+impl Roles {
+    pub fn all(key: Pubkey) -> Self {
+        Self { price_keeper: key, finalizer: key, treasurer: key, moderator: key }
+    }
+
+    pub fn get_mut(&mut self, role: Role) -> &mut Pubkey {
+        match role {
+            Role::PriceKeeper => &mut self.price_keeper,
+            Role::Finalizer => &mut self.finalizer,
+            Role::Treasurer => &mut self.treasurer,
+            Role::Moderator => &mut self.moderator,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct TeamEntry {
+    pub team: Pubkey,
+    pub profit: i128,               // sum of tallied member profits
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct RecentResult {
+    pub competition: Pubkey,
+    pub profit: i128,
+    pub won: bool,                  // set when the competition settles
+    pub finished_at: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct LeaderboardEntry {
+    pub user: Pubkey,
+    pub score: i128,                // ranking key, per config.scoring
+    pub profit: i128,
+    pub trade_count: u32,
+    pub reached_at: i64,            // last trade ts
+}
+
+impl LeaderboardEntry {
+    pub fn ranks_above(&self, other: &LeaderboardEntry, policy: TieBreak) -> bool {
+        if self.score != other.score {
+            return self.score > other.score;
+        }
+        let key = match policy {
+            TieBreak::CoWinners => Ordering::Equal,
+            TieBreak::EarliestToProfit => self.reached_at.cmp(&other.reached_at),
+            TieBreak::FewestTrades => self.trade_count.cmp(&other.trade_count),
+        };
+        key.then_with(|| self.user.cmp(&other.user)).is_lt()
+    }
+}
+
+/// The layout is not versioned. Accounts created before `roles` (and the other
+/// fields added alongside it) no longer deserialize; finish them on the old
+/// program build and re-create them – there is no in-place migration.
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct Competition {
     pub authority: Pubkey,          // admin
     pub usdc_mint: Pubkey,
@@ -25,21 +258,180 @@ pub struct Competition {
     pub end_time: i64,              // absolute timestamp
     pub phase: CompetitionPhase,
     pub state_root: [u8; 32],       // Merkle root of final ER state
-    pub winner: Pubkey,             // set in final_commit
+    pub winner: Pubkey,             // leaderboard head (first of winner_count)
     pub winner_profit: i128,
+    pub winner_score: i128,
     pub challenge_deadline: i64,    // unix ts when challenge window closes
+    pub config: CompetitionConfig,
+    pub participant_count: u32,     // registered via delegate_accounts
+    pub submission_count: u32,      // positions submitted (OnChain mode)
+    pub open_challenges: u32,       // bonds escrowed, not yet resolved
+    pub leaderboard: [LeaderboardEntry; LEADERBOARD_SIZE],
+    pub leaderboard_len: u8,
+    pub winner_count: u8,           // leading entries that share the win
+    pub prize_pool: u64,            // USDC funded into the prize vault
+    pub prize_claimed: u16,         // bit i = winners()[i] has claimed
+    pub team_leaderboard: [TeamEntry; TEAM_LEADERBOARD_SIZE],
+    pub team_leaderboard_len: u8,
+    pub untallied_members: u32,     // team members not yet tallied; settlement waits for 0
+    pub winning_team: Pubkey,       // team_leaderboard head, set at settlement
+    pub season: Pubkey,             // default = not part of a season
+    pub season_awarded: u16,        // bit i = leaderboard[i] got season points
+    pub season_pending: bool,       // counted in season.pending_competitions
+    pub registration_deadline: i64, // 0 = open until the competition ends
+    pub allowlist_root: [u8; 32],   // zero = anyone may register
+    pub field_rating_sum: u64,      // entrants' ratings at registration
+    pub review_deadline: i64,       // settlement + REVIEW_WINDOW; overturns before, payouts after
+    #[max_len(MAX_URI_TEMPLATE_LEN)]
+    pub uri_template: String,       // trophy metadata URI, see render_uri
+    pub roles: Roles,               // admin keys; `authority` only grants them
     pub bump: u8,
 }
 
+/// Fill `{competition}` / `{winner}` in a trophy URI template.
+pub fn render_uri(template: &str, competition: &Pubkey, winner: &Pubkey) -> String {
+    template
+        .replace("{competition}", &competition.to_string())
+        .replace("{winner}", &winner.to_string())
+}
+
+impl Competition {
+    pub fn winners(&self) -> &[LeaderboardEntry] {
+        &self.leaderboard[..self.winner_count as usize]
+    }
+
+    pub fn is_winner(&self, user: &Pubkey) -> bool {
+        self.winners().iter().any(|e| e.user == *user)
+    }
+
+    /// Leaderboard slot of `user`, if ranked.
+    pub fn slot_of(&self, user: &Pubkey) -> Option<usize> {
+        self.leaderboard[..self.leaderboard_len as usize]
+            .iter()
+            .position(|e| e.user == *user)
+    }
+
+    /// Ranked within the best tenth of the field (at least the top spot).
+    pub fn is_top_decile(&self, user: &Pubkey) -> bool {
+        self.rank_of(user)
+            .is_some_and(|r| (r as u64 + 1) * 10 <= self.participant_count as u64 + 9)
+    }
+
+    /// 0-based finishing rank of `user`; co-winners all share rank 0.
+    pub fn rank_of(&self, user: &Pubkey) -> Option<usize> {
+        self.slot_of(user)
+            .map(|slot| if slot < self.winner_count as usize { 0 } else { slot })
+    }
+
+    /// Every ranked trader has received their season points.
+    pub fn season_fully_awarded(&self) -> bool {
+        let ranked = (1u32 << self.leaderboard_len) - 1;
+        self.season_awarded as u32 & ranked == ranked
+    }
+
+    /// Insert `entry` in rank order; whatever is pushed past the end drops off.
+    pub fn record(&mut self, entry: LeaderboardEntry) {
+        let len = self.leaderboard_len as usize;
+        let idx = self.leaderboard[..len]
+            .iter()
+            .position(|e| entry.ranks_above(e, self.config.tie_break))
+            .unwrap_or(len);
+        if idx >= LEADERBOARD_SIZE {
+            return;
+        }
+        let new_len = (len + 1).min(LEADERBOARD_SIZE);
+        self.leaderboard[idx..new_len].rotate_right(1);
+        self.leaderboard[idx] = entry;
+        self.leaderboard_len = new_len as u8;
+        self.refresh_winners();
+    }
+
+    /// Drop `user` from the board; everyone below moves up a slot.
+    pub fn remove(&mut self, user: &Pubkey) {
+        let Some(slot) = self.slot_of(user) else {
+            return;
+        };
+        let len = self.leaderboard_len as usize;
+        self.leaderboard[slot..len].rotate_left(1);
+        self.leaderboard[len - 1] = LeaderboardEntry::default();
+        self.leaderboard_len -= 1;
+        self.refresh_winners();
+    }
+
+    /// Re-derive `winner_count` and the `winner` fields from the board head.
+    pub fn refresh_winners(&mut self) {
+        let board = &self.leaderboard[..self.leaderboard_len as usize];
+        let Some(top) = board.first().copied() else {
+            self.winner_count = 0;
+            return;
+        };
+        self.winner = top.user;
+        self.winner_profit = top.profit;
+        self.winner_score = top.score;
+        self.winner_count = match self.config.tie_break {
+            TieBreak::CoWinners => board.iter().take_while(|e| e.score == top.score).count() as u8,
+            _ => 1,
+        };
+    }
+
+    /// Re-rank `team` at its new total. Ties go to the lower team key.
+    pub fn record_team(&mut self, team: Pubkey, profit: i128) {
+        let mut len = self.team_leaderboard_len as usize;
+        if let Some(old) = self.team_leaderboard[..len].iter().position(|e| e.team == team) {
+            self.team_leaderboard[old..len].rotate_left(1);
+            len -= 1;
+        }
+        let idx = self.team_leaderboard[..len]
+            .iter()
+            .position(|e| profit > e.profit || (profit == e.profit && team < e.team))
+            .unwrap_or(len);
+        if idx >= TEAM_LEADERBOARD_SIZE {
+            self.team_leaderboard_len = len as u8;
+            return;
+        }
+        let new_len = (len + 1).min(TEAM_LEADERBOARD_SIZE);
+        self.team_leaderboard[idx..new_len].rotate_right(1);
+        self.team_leaderboard[idx] = TeamEntry { team, profit };
+        self.team_leaderboard_len = new_len as u8;
+    }
+
+    /// Each winner's cut of the prize pool (dust stays in the vault).
+    pub fn prize_share(&self) -> u64 {
+        self.prize_pool / (self.winner_count.max(1) as u64)
+    }
+}
+
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct Position {
     pub competition: Pubkey,
     pub user: Pubkey,
-    pub usdc_ata: Pubkey,           // delegated ATA (USDC)
     pub usdc_balance: u128,         // synthetic USDC balance (6-dec)
     pub initial_value: u128,
     pub current_value: u128,
+    pub verified: bool,             // leaf proven under competition.state_root
+    pub submitted: bool,            // counted by submit_position
+    pub trade_count: u32,
+    pub buy_count: u32,
+    pub sell_count: u32,
+    pub total_volume: u128,         // notional traded (6-dec USDC)
+    pub first_trade_at: i64,
+    pub last_trade_at: i64,         // when current profit was reached
+    pub active_days: u16,           // distinct UTC days traded
+    pub last_active_day: i64,       // unix day of the latest trade
+    pub peak_value: u128,
+    pub max_drawdown: u128,         // largest peak-to-trough drop of current_value
+    pub sample_count: u32,          // equity samples taken
+    pub last_sample_value: u128,
+    pub last_sample_at: i64,
+    pub return_sum: i128,           // sum of per-sample returns (bps)
+    pub return_sq_sum: u128,        // sum of squared per-sample returns
+    pub achievements: u8,           // ACHIEVEMENT_* bits unlocked
+    pub achievements_claimed: u8,   // bits already minted as badges
+    pub profit_streak: u16,         // consecutive trades that raised profit
+    pub entry_rating: u32,          // trader's rating when registering
+    pub rating_applied: bool,       // counted by update_rating
+    pub overturned: bool,           // struck from the results by overturn_result
     pub bump: u8,
 }
 
@@ -48,6 +440,346 @@ impl Position {
     pub fn profit(&self) -> i128 {
         self.current_value as i128 - self.initial_value as i128
     }
+
+    /// Leaf hash of this position as committed in `final_commit`.
+    pub fn leaf(&self) -> [u8; 32] {
+        state_root::position_leaf(&state_root::PositionLeaf {
+            user: self.user.to_bytes(),
+            usdc_balance: self.usdc_balance,
+            initial_value: self.initial_value,
+            current_value: self.current_value,
+            trade_count: self.trade_count,
+            total_volume: self.total_volume,
+            active_days: self.active_days,
+            last_trade_at: self.last_trade_at,
+            max_drawdown: self.max_drawdown,
+            sample_count: self.sample_count,
+            return_sum: self.return_sum,
+            return_sq_sum: self.return_sq_sum,
+        })
+    }
+
+    /// Return on the starting balance, in basis points.
+    pub fn roi_bps(&self) -> i128 {
+        self.profit().saturating_mul(10_000) / (self.initial_value.max(1) as i128)
+    }
+
+    pub fn is_eligible(&self, rules: &EligibilityRules) -> bool {
+        self.trade_count >= rules.min_trades
+            && self.total_volume >= rules.min_volume
+            && self.active_days >= rules.min_active_days
+    }
+
+    /// Ranking score under the competition's scoring mode. Every winner
+    /// comparison goes through this.
+    pub fn score(&self, config: &CompetitionConfig) -> i128 {
+        let profit = self.profit();
+        match config.scoring {
+            ScoringMode::AbsolutePnl => profit,
+            ScoringMode::Roi => self.roi_bps(),
+            ScoringMode::Sharpe => {
+                if self.sample_count < 2 {
+                    return 0;
+                }
+                let n = self.sample_count as i128;
+                let mean = self.return_sum / n;
+                let variance = (self.return_sq_sum / n as u128)
+                    .saturating_sub(mean.unsigned_abs().saturating_pow(2));
+                let stddev = variance.isqrt().max(SHARPE_MIN_STDDEV_BPS) as i128;
+                mean.saturating_mul(10_000) / stddev
+            }
+            ScoringMode::DrawdownPenalized => {
+                let penalty = self
+                    .max_drawdown
+                    .saturating_mul(config.drawdown_penalty_bps as u128)
+                    / 10_000;
+                profit.saturating_sub(penalty.min(i128::MAX as u128) as i128)
+            }
+        }
+    }
+
+    /// Unlock the trading achievements; `prev_profit` is profit before this trade.
+    pub fn track_achievements(&mut self, prev_profit: i128) {
+        self.achievements |= ACHIEVEMENT_FIRST_TRADE;
+        if self.profit() > prev_profit {
+            self.profit_streak = self.profit_streak.saturating_add(1);
+        } else {
+            self.profit_streak = 0;
+        }
+        if self.profit_streak >= PROFIT_STREAK_LEN {
+            self.achievements |= ACHIEVEMENT_PROFIT_STREAK;
+        }
+        if self.current_value >= self.initial_value.saturating_mul(2) {
+            self.achievements |= ACHIEVEMENT_DOUBLED;
+        }
+    }
+
+    /// Update peak / drawdown and take the equity samples due since the last
+    /// one, on a fixed grid from `trading_start`. Equity only moves on
+    /// trades, so intervals without one are sampled as flat.
+    pub fn track_equity(&mut self, now: i64, trading_start: i64) -> Result<()> {
+        if self.current_value > self.peak_value {
+            self.peak_value = self.current_value;
+        }
+        self.max_drawdown = self.max_drawdown.max(self.peak_value - self.current_value);
+
+        let since = if self.last_sample_at == 0 { trading_start } else { self.last_sample_at };
+        let intervals = (now - since) / EQUITY_SAMPLE_INTERVAL;
+        if intervals >= 1 {
+            let prev = self.last_sample_value.max(1) as i128;
+            let ret = (self.current_value as i128 - prev)
+                .checked_mul(10_000)
+                .ok_or(CompetitionError::CalculationError)?
+                / prev;
+            self.return_sum = self
+                .return_sum
+                .checked_add(ret)
+                .ok_or(CompetitionError::CalculationError)?;
+            self.return_sq_sum = self
+                .return_sq_sum
+                .checked_add(ret.unsigned_abs().saturating_pow(2))
+                .ok_or(CompetitionError::CalculationError)?;
+            // the earlier intervals were idle – zero returns only add to the count
+            self.sample_count = u32::try_from(intervals)
+                .ok()
+                .and_then(|n| self.sample_count.checked_add(n))
+                .ok_or(CompetitionError::CalculationError)?;
+            self.last_sample_value = self.current_value;
+            self.last_sample_at = since + intervals * EQUITY_SAMPLE_INTERVAL;
+        }
+        Ok(())
+    }
+
+    pub fn entry(&self, config: &CompetitionConfig) -> LeaderboardEntry {
+        LeaderboardEntry {
+            user: self.user,
+            score: self.score(config),
+            profit: self.profit(),
+            trade_count: self.trade_count,
+            reached_at: self.last_trade_at,
+        }
+    }
+}
+
+/// Trophy minted for the top three finishing ranks.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TrophyTier {
+    Gold,
+    Silver,
+    Bronze,
+}
+
+impl TrophyTier {
+    /// Tier for a 0-based rank from `Competition::rank_of`.
+    pub fn from_rank(rank: usize) -> Option<Self> {
+        match rank {
+            0 => Some(Self::Gold),
+            1 => Some(Self::Silver),
+            2 => Some(Self::Bronze),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Gold => "Cypherpunk Gold Trophy",
+            Self::Silver => "Cypherpunk Silver Trophy",
+            Self::Bronze => "Cypherpunk Bronze Trophy",
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Gold => "CYGLD",
+            Self::Silver => "CYSLV",
+            Self::Bronze => "CYBRZ",
+        }
+    }
+
+    pub fn color(&self) -> &'static str {
+        match self {
+            Self::Gold => "#D4AF37",
+            Self::Silver => "#C0C0C0",
+            Self::Bronze => "#CD7F32",
+        }
+    }
+}
+
+/// Series of competitions scored with a points table.
+#[account]
+#[derive(InitSpace)]
+pub struct Season {
+    pub authority: Pubkey,
+    pub season_id: u64,
+    pub usdc_mint: Pubkey,
+    pub points_table: [u32; LEADERBOARD_SIZE], // points for rank 1..=LEADERBOARD_SIZE
+    pub competition_count: u32,
+    pub pending_competitions: u32,  // linked but not yet fully awarded; settle waits for 0
+    pub champion: Pubkey,           // most points so far, ties to the lower key
+    pub champion_points: u64,
+    pub settled: bool,
+    pub prize_pool: u64,            // USDC in the season vault
+    pub prize_claimed: bool,
+    pub bump: u8,
+}
+
+impl Season {
+    /// Points for a 0-based rank from `Competition::rank_of`.
+    pub fn points_for(&self, rank: usize) -> u32 {
+        self.points_table.get(rank).copied().unwrap_or(0)
+    }
+
+    /// Stop waiting on `comp` – fully awarded, settled empty or unlinked.
+    pub fn release(&mut self, comp: &mut Competition) -> Result<()> {
+        if comp.season_pending {
+            self.pending_competitions = self
+                .pending_competitions
+                .checked_sub(1)
+                .ok_or(CompetitionError::CalculationError)?;
+            comp.season_pending = false;
+        }
+        Ok(())
+    }
+}
+
+/// Escrowed challenge; one per (competition, challenger).
+#[account]
+#[derive(InitSpace)]
+pub struct Challenge {
+    pub competition: Pubkey,
+    pub challenger: Pubkey,
+    pub bond: u64,
+    pub opened_at: i64,
+    pub committed_head: Option<LeaderboardEntry>,  // board head when opened; judged against this
+    pub bump: u8,
+}
+
+impl Challenge {
+    /// `entry` outranks the head the challenge was opened against – later
+    /// resolutions moving the board don't change the verdict.
+    pub fn is_upheld_by(&self, entry: &LeaderboardEntry, policy: TieBreak) -> bool {
+        match self.committed_head {
+            None => true,
+            Some(head) => {
+                entry.ranks_above(&head, policy)
+                    || (policy == TieBreak::CoWinners && entry.score == head.score)
+            }
+        }
+    }
+}
+
+/// A trader's running total within a season.
+#[account]
+#[derive(InitSpace)]
+pub struct SeasonStanding {
+    pub season: Pubkey,
+    pub trader: Pubkey,
+    pub points: u64,
+    pub competitions_scored: u32,
+    pub bump: u8,
+}
+
+/// Desk / squad competing alongside the individual ranking.
+#[account]
+#[derive(InitSpace)]
+pub struct Team {
+    pub competition: Pubkey,
+    pub captain: Pubkey,
+    #[max_len(MAX_TEAM_NAME_LEN)]
+    pub name: String,
+    pub members: [Pubkey; MAX_TEAM_SIZE],
+    pub member_count: u8,
+    pub tallied: u8,                // bit i = members[i]'s position counted
+    pub total_profit: i128,
+    pub bump: u8,
+}
+
+/// One per (competition, wallet) – stops a trader joining two teams.
+/// Kept apart from Position, which sits in the ER while teams form.
+#[account]
+#[derive(InitSpace)]
+pub struct TeamMember {
+    pub team: Pubkey,
+    pub user: Pubkey,
+    pub bump: u8,
+}
+
+/// Per-wallet identity carried across competitions. Entries, wins, best
+/// profit and recent results are written only by the `update_rating` crank
+/// once a competition settles – `user_undelegate` and `settle_competition`
+/// leave the profile alone.
+#[account]
+#[derive(InitSpace)]
+pub struct TraderProfile {
+    pub wallet: Pubkey,
+    #[max_len(MAX_HANDLE_LEN)]
+    pub handle: String,             // display name, empty until set
+    pub rating: u32,                // Elo, starts at INITIAL_RATING
+    pub peak_rating: u32,
+    pub rated_competitions: u32,
+    pub competitions_entered: u32,  // settled and rated
+    pub wins: u32,
+    pub best_profit: i128,
+    pub recent: [RecentResult; RECENT_RESULTS],
+    pub recent_len: u8,
+    pub recent_head: u8,            // next slot to overwrite
+    pub bump: u8,
+}
+
+impl TraderProfile {
+    /// Log a finished competition, crediting the win if `won`; repeat calls
+    /// for the same one are ignored.
+    pub fn record_result(&mut self, competition: Pubkey, profit: i128, won: bool, now: i64) {
+        if self.recent[..self.recent_len as usize].iter().any(|r| r.competition == competition) {
+            return;
+        }
+        if self.competitions_entered == 0 || profit > self.best_profit {
+            self.best_profit = profit;
+        }
+        self.competitions_entered += 1;
+        if won {
+            self.wins += 1;
+        }
+
+        let slot = self.recent_head as usize;
+        self.recent[slot] = RecentResult { competition, profit, won, finished_at: now };
+        self.recent_head = ((slot + 1) % RECENT_RESULTS) as u8;
+        self.recent_len = (self.recent_len + 1).min(RECENT_RESULTS as u8);
+    }
+}
+
+/// Expected score (bps) of `rating` against `opponents`, interpolated from the table.
+pub fn expected_score_bps(rating: u32, opponents: u32) -> i64 {
+    let gap = opponents as i64 - rating as i64;
+    let max_gap = EXPECTED_SCORE_STEP * (EXPECTED_SCORE_BPS.len() as i64 - 1);
+    let abs = gap.abs().min(max_gap);
+    let i = (abs / EXPECTED_SCORE_STEP) as usize;
+    let frac = abs % EXPECTED_SCORE_STEP;
+    let below = EXPECTED_SCORE_BPS[i];
+    let above = EXPECTED_SCORE_BPS[(i + 1).min(EXPECTED_SCORE_BPS.len() - 1)];
+    let e = below - (below - above) * frac / EXPECTED_SCORE_STEP;
+    if gap >= 0 { e } else { 10_000 - e }
+}
+
+/// Elo change for finishing at `rank_x2 / 2` (1-based, may be a half rank)
+/// in a field of `n >= 2`, rounded half away from zero.
+pub fn rating_delta(rating: u32, opponents: u32, rank_x2: u64, n: u32) -> i64 {
+    let n = n as i64;
+    let actual = (2 * n - rank_x2 as i64) * 10_000 / (2 * (n - 1));
+    let scaled = RATING_K * (actual - expected_score_bps(rating, opponents));
+    (scaled + scaled.signum() * 5_000) / 10_000
+}
+
+/// Proof a trader finished a competition; one per (competition, wallet).
+#[account]
+#[derive(InitSpace)]
+pub struct ParticipationRecord {
+    pub competition: Pubkey,
+    pub user: Pubkey,
+    pub rank: u16,                  // 1-based, 0 = outside the leaderboard
+    pub profit: i128,
+    pub claimed_at: i64,
+    pub bump: u8,
 }
 
 #[account]
@@ -90,4 +822,610 @@ pub enum CompetitionError {
     InsufficientFunds,
     #[msg("Arithmetic overflow")]
     CalculationError,
-}
\ No newline at end of file
+    #[msg("State root not committed")]
+    NoStateRoot,
+    #[msg("Invalid Merkle proof")]
+    InvalidProof,
+    #[msg("Position already verified")]
+    AlreadyVerified,
+    #[msg("Challenge window closed")]
+    ChallengeWindowClosed,
+    #[msg("Challenges still open")]
+    ChallengesOpen,
+    #[msg("Not available in this finalization mode")]
+    WrongFinalizationMode,
+    #[msg("Submission window closed")]
+    SubmissionClosed,
+    #[msg("Position already submitted")]
+    AlreadySubmitted,
+    #[msg("Not a winner of this competition")]
+    NotWinner,
+    #[msg("Prize already claimed")]
+    AlreadyClaimed,
+    #[msg("Trader does not meet the eligibility rules")]
+    NotEligible,
+    #[msg("Registration closed")]
+    RegistrationClosed,
+    #[msg("Wallet not on the allowlist")]
+    NotAllowlisted,
+    #[msg("Competition is full")]
+    CompetitionFull,
+    #[msg("Entry requirements not met")]
+    EntryGateNotMet,
+    #[msg("Invalid competition config")]
+    InvalidConfig,
+    #[msg("Teams are disabled for this competition")]
+    TeamsDisabled,
+    #[msg("Team is full")]
+    TeamFull,
+    #[msg("Name too long")]
+    NameTooLong,
+    #[msg("Member already tallied")]
+    AlreadyTallied,
+    #[msg("Team members still need to be tallied")]
+    TeamsUntallied,
+    #[msg("Runner-up position is invalid, duplicated or outranks the winner")]
+    InvalidRunnerUp,
+    #[msg("Result already overturned")]
+    AlreadyOverturned,
+    #[msg("Prizes or season points already paid out")]
+    ResultsDistributed,
+    #[msg("Trader not registered")]
+    NotRegistered,
+    #[msg("Season already settled")]
+    SeasonSettled,
+    #[msg("Season competitions still need settling and awarding")]
+    CompetitionsPending,
+    #[msg("Season not settled")]
+    SeasonNotSettled,
+    #[msg("Competition already belongs to a season")]
+    AlreadyInSeason,
+    #[msg("Competition is not part of this season")]
+    WrongSeason,
+    #[msg("Season points already awarded")]
+    AlreadyAwarded,
+    #[msg("Rating already applied")]
+    AlreadyRated,
+    #[msg("URI template too long")]
+    UriTooLong,
+    #[msg("No URI template set")]
+    NoUriTemplate,
+    #[msg("Trophy holder's result has not been overturned")]
+    NotOverturned,
+    #[msg("Unknown achievement")]
+    UnknownAchievement,
+    #[msg("Achievement not unlocked")]
+    AchievementLocked,
+    #[msg("This competition awards a different kind of trophy")]
+    WrongTrophyKind,
+    #[msg("Results are still under review")]
+    UnderReview,
+    #[msg("Review window has closed")]
+    ReviewClosed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: u128 = 1_000_000_000;
+
+    fn config(scoring: ScoringMode) -> CompetitionConfig {
+        CompetitionConfig { scoring, ..Default::default() }
+    }
+
+    fn competition(tie_break: TieBreak) -> Competition {
+        Competition {
+            config: CompetitionConfig { tie_break, ..Default::default() },
+            ..Default::default()
+        }
+    }
+
+    /// Entry with a key whose first byte orders it against the others.
+    fn entry(key: u8, score: i128, trade_count: u32, reached_at: i64) -> LeaderboardEntry {
+        LeaderboardEntry {
+            user: Pubkey::new_from_array([key; 32]),
+            score,
+            profit: score,
+            trade_count,
+            reached_at,
+        }
+    }
+
+    fn users(comp: &Competition) -> Vec<u8> {
+        comp.leaderboard[..comp.leaderboard_len as usize]
+            .iter()
+            .map(|e| e.user.to_bytes()[0])
+            .collect()
+    }
+
+    fn position(current_value: u128) -> Position {
+        Position {
+            user: Pubkey::new_unique(),
+            initial_value: START,
+            current_value,
+            peak_value: START,
+            last_sample_value: START,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn absolute_pnl_and_roi_scores() {
+        let pos = position(START + START / 10);
+        assert_eq!(pos.score(&config(ScoringMode::AbsolutePnl)), (START / 10) as i128);
+        assert_eq!(pos.score(&config(ScoringMode::Roi)), 1_000);
+        assert_eq!(position(START / 2).score(&config(ScoringMode::Roi)), -5_000);
+    }
+
+    fn active(trade_count: u32, total_volume: u128, active_days: u16) -> Position {
+        Position { trade_count, total_volume, active_days, ..position(START) }
+    }
+
+    #[test]
+    fn eligibility_defaults_to_open() {
+        assert!(active(0, 0, 0).is_eligible(&EligibilityRules::default()));
+    }
+
+    #[test]
+    fn eligibility_min_trades_boundary() {
+        let rules = EligibilityRules { min_trades: 5, ..Default::default() };
+        assert!(!active(4, 0, 0).is_eligible(&rules));
+        assert!(active(5, 0, 0).is_eligible(&rules));
+    }
+
+    #[test]
+    fn eligibility_min_volume_boundary() {
+        let rules = EligibilityRules { min_volume: START, ..Default::default() };
+        assert!(!active(0, START - 1, 0).is_eligible(&rules));
+        assert!(active(0, START, 0).is_eligible(&rules));
+    }
+
+    #[test]
+    fn eligibility_min_active_days_boundary() {
+        let rules = EligibilityRules { min_active_days: 3, ..Default::default() };
+        assert!(!active(0, 0, 2).is_eligible(&rules));
+        assert!(active(0, 0, 3).is_eligible(&rules));
+    }
+
+    #[test]
+    fn eligibility_needs_every_threshold() {
+        let rules = EligibilityRules { min_trades: 5, min_volume: START, min_active_days: 3 };
+        assert!(active(5, START, 3).is_eligible(&rules));
+        assert!(!active(5, START, 2).is_eligible(&rules));
+        assert!(!active(4, START, 3).is_eligible(&rules));
+    }
+
+    #[test]
+    fn sharpe_needs_two_samples() {
+        let mut pos = position(START);
+        pos.sample_count = 1;
+        pos.return_sum = 500;
+        pos.return_sq_sum = 250_000;
+        assert_eq!(pos.score(&config(ScoringMode::Sharpe)), 0);
+    }
+
+    #[test]
+    fn sharpe_is_mean_over_stddev() {
+        // returns of 300 and 100 bps: mean 200, stddev 100
+        let mut pos = position(START);
+        pos.sample_count = 2;
+        pos.return_sum = 400;
+        pos.return_sq_sum = 300 * 300 + 100 * 100;
+        assert_eq!(pos.score(&config(ScoringMode::Sharpe)), 20_000);
+
+        // flat returns – stddev floored, so they don't beat the varied record
+        pos.return_sum = 200;
+        pos.return_sq_sum = 2 * 100 * 100;
+        assert_eq!(pos.score(&config(ScoringMode::Sharpe)), 10_000);
+    }
+
+    #[test]
+    fn drawdown_penalty_scales_with_bps() {
+        let mut pos = position(START + 1_000);
+        pos.max_drawdown = 400;
+        let mut cfg = config(ScoringMode::DrawdownPenalized);
+        assert_eq!(pos.score(&cfg), 1_000);
+        cfg.drawdown_penalty_bps = 5_000;
+        assert_eq!(pos.score(&cfg), 800);
+        cfg.drawdown_penalty_bps = 30_000;
+        assert_eq!(pos.score(&cfg), -200);
+    }
+
+    #[test]
+    fn track_equity_follows_peak_and_drawdown() {
+        let mut pos = position(START + 500);
+        pos.track_equity(0, 0).unwrap();
+        assert_eq!(pos.peak_value, START + 500);
+        pos.current_value = START - 300;
+        pos.track_equity(1, 0).unwrap();
+        assert_eq!(pos.max_drawdown, 800);
+        pos.current_value = START + 100;
+        pos.track_equity(2, 0).unwrap();
+        assert_eq!((pos.peak_value, pos.max_drawdown), (START + 500, 800));
+    }
+
+    #[test]
+    fn track_equity_samples_once_per_interval() {
+        let mut pos = position(START + START / 100);
+        pos.track_equity(EQUITY_SAMPLE_INTERVAL, 0).unwrap();
+        assert_eq!((pos.sample_count, pos.return_sum, pos.return_sq_sum), (1, 100, 10_000));
+
+        // too soon – no new sample
+        pos.current_value = START;
+        pos.track_equity(EQUITY_SAMPLE_INTERVAL + 1, 0).unwrap();
+        assert_eq!(pos.sample_count, 1);
+
+        pos.track_equity(2 * EQUITY_SAMPLE_INTERVAL, 0).unwrap();
+        assert_eq!(pos.sample_count, 2);
+        assert_eq!(pos.return_sum, 100 - 99);
+        assert_eq!(pos.last_sample_value, START);
+    }
+
+    #[test]
+    fn track_equity_samples_idle_intervals_as_flat() {
+        let start = 1_000;
+        let mut pos = position(START + START / 100);
+        // first trade three and a half intervals into trading
+        pos.track_equity(start + 7 * EQUITY_SAMPLE_INTERVAL / 2, start).unwrap();
+        assert_eq!((pos.sample_count, pos.return_sum), (3, 100));
+        assert_eq!(pos.last_sample_at, start + 3 * EQUITY_SAMPLE_INTERVAL);
+
+        // closing out at the end adds the idle tail
+        pos.track_equity(start + 10 * EQUITY_SAMPLE_INTERVAL, start).unwrap();
+        assert_eq!((pos.sample_count, pos.return_sum), (10, 100));
+    }
+
+    #[test]
+    fn leaf_commits_to_scoring_inputs() {
+        let pos = position(START + 7);
+        let mut drawn = pos.clone();
+        drawn.max_drawdown = 1;
+        assert_ne!(pos.leaf(), drawn.leaf());
+        let mut sampled = pos.clone();
+        sampled.return_sq_sum = 1;
+        assert_ne!(pos.leaf(), sampled.leaf());
+    }
+
+    #[test]
+    fn ranks_above_applies_tie_break() {
+        let early = entry(2, 100, 9, 10);
+        let late = entry(1, 100, 3, 20);
+        assert!(entry(3, 101, 0, 0).ranks_above(&early, TieBreak::FewestTrades));
+        assert!(early.ranks_above(&late, TieBreak::EarliestToProfit));
+        assert!(late.ranks_above(&early, TieBreak::FewestTrades));
+        // CoWinners and full ties fall back to key order
+        assert!(late.ranks_above(&early, TieBreak::CoWinners));
+        assert!(!early.ranks_above(&late, TieBreak::CoWinners));
+        assert!(!early.ranks_above(&early, TieBreak::EarliestToProfit));
+    }
+
+    #[test]
+    fn record_keeps_rank_order_and_drops_the_tail() {
+        let mut comp = competition(TieBreak::FewestTrades);
+        for k in 1..=LEADERBOARD_SIZE as u8 + 2 {
+            comp.record(entry(k, k as i128 * 10, 0, 0));
+        }
+        assert_eq!(comp.leaderboard_len as usize, LEADERBOARD_SIZE);
+        assert_eq!(users(&comp), (3..=12).rev().collect::<Vec<_>>());
+        assert_eq!((comp.winner, comp.winner_score, comp.winner_count), (comp.leaderboard[0].user, 120, 1));
+
+        // below the last entry of a full board – ignored
+        comp.record(entry(20, 0, 0, 0));
+        assert_eq!(comp.slot_of(&Pubkey::new_from_array([20; 32])), None);
+    }
+
+    #[test]
+    fn refresh_winners_counts_co_winners() {
+        let mut comp = competition(TieBreak::CoWinners);
+        comp.record(entry(5, 100, 0, 0));
+        comp.record(entry(3, 100, 0, 0));
+        comp.record(entry(4, 90, 0, 0));
+        comp.record(entry(1, 100, 0, 0));
+        assert_eq!(users(&comp), vec![1, 3, 5, 4]);
+        assert_eq!(comp.winner_count, 3);
+        assert_eq!(comp.winner, Pubkey::new_from_array([1; 32]));
+        assert!(comp.is_winner(&Pubkey::new_from_array([5; 32])));
+        assert!(!comp.is_winner(&Pubkey::new_from_array([4; 32])));
+
+        comp.prize_pool = 1_000;
+        assert_eq!(comp.prize_share(), 333);
+    }
+
+    #[test]
+    fn tie_break_policies_pick_a_single_winner() {
+        let mut comp = competition(TieBreak::EarliestToProfit);
+        comp.record(entry(1, 100, 9, 50));
+        comp.record(entry(2, 100, 3, 40));
+        assert_eq!((users(&comp), comp.winner_count), (vec![2, 1], 1));
+
+        let mut comp = competition(TieBreak::FewestTrades);
+        comp.record(entry(1, 100, 9, 50));
+        comp.record(entry(2, 100, 12, 40));
+        assert_eq!((users(&comp), comp.winner_count), (vec![1, 2], 1));
+    }
+
+    #[test]
+    fn rank_of_shares_rank_zero_among_co_winners() {
+        let mut comp = competition(TieBreak::CoWinners);
+        comp.record(entry(1, 100, 0, 0));
+        comp.record(entry(2, 100, 0, 0));
+        comp.record(entry(3, 50, 0, 0));
+        let key = |k: u8| Pubkey::new_from_array([k; 32]);
+        assert_eq!(comp.rank_of(&key(1)), Some(0));
+        assert_eq!(comp.rank_of(&key(2)), Some(0));
+        assert_eq!(comp.rank_of(&key(3)), Some(2));
+        assert_eq!(comp.rank_of(&key(9)), None);
+    }
+
+    #[test]
+    fn challenges_are_judged_against_the_head_they_were_opened_on() {
+        let mut comp = competition(TieBreak::FewestTrades);
+        comp.record(entry(1, 100, 0, 0));
+        let opened = |comp: &Competition| Challenge {
+            competition: Pubkey::default(),
+            challenger: Pubkey::default(),
+            bond: 0,
+            opened_at: 0,
+            committed_head: Some(comp.leaderboard[0]),
+            bump: 0,
+        };
+        let (a, b) = (entry(2, 110, 0, 0), entry(3, 120, 0, 0));
+        let (challenge_a, challenge_b) = (opened(&comp), opened(&comp));
+
+        // B resolves first; A still beat the committed head
+        assert!(challenge_b.is_upheld_by(&b, comp.config.tie_break));
+        comp.record(b);
+        assert!(challenge_a.is_upheld_by(&a, comp.config.tie_break));
+        comp.record(a);
+        assert_eq!(users(&comp), vec![3, 2, 1]);
+
+        assert!(!challenge_a.is_upheld_by(&entry(4, 90, 0, 0), comp.config.tie_break));
+        assert!(Challenge { committed_head: None, ..challenge_a }.is_upheld_by(&a, TieBreak::CoWinners));
+    }
+
+    #[test]
+    fn empty_board_has_no_winners() {
+        let mut comp = competition(TieBreak::CoWinners);
+        comp.refresh_winners();
+        assert_eq!(comp.winner_count, 0);
+        assert!(comp.winners().is_empty());
+    }
+
+    #[test]
+    fn record_team_reranks_and_truncates() {
+        let team = |k: u8| Pubkey::new_from_array([k; 32]);
+        let teams = |comp: &Competition| -> Vec<u8> {
+            comp.team_leaderboard[..comp.team_leaderboard_len as usize]
+                .iter()
+                .map(|e| e.team.to_bytes()[0])
+                .collect()
+        };
+        let mut comp = Competition::default();
+        for k in 1..=TEAM_LEADERBOARD_SIZE as u8 {
+            comp.record_team(team(k), k as i128);
+        }
+        assert_eq!(teams(&comp), vec![5, 4, 3, 2, 1]);
+
+        // a later tally moves the team rather than duplicating it
+        comp.record_team(team(2), 10);
+        assert_eq!(teams(&comp), vec![2, 5, 4, 3, 1]);
+
+        // ties go to the lower key; the weakest drops off a full board
+        comp.record_team(team(9), 4);
+        assert_eq!(teams(&comp), vec![2, 5, 4, 9, 3]);
+        comp.record_team(team(8), -1);
+        assert_eq!(teams(&comp), vec![2, 5, 4, 9, 3]);
+    }
+
+    #[test]
+    fn trophy_tiers_cover_the_podium() {
+        assert!(matches!(TrophyTier::from_rank(0), Some(TrophyTier::Gold)));
+        assert!(matches!(TrophyTier::from_rank(1), Some(TrophyTier::Silver)));
+        assert!(matches!(TrophyTier::from_rank(2), Some(TrophyTier::Bronze)));
+        assert!(TrophyTier::from_rank(3).is_none());
+    }
+
+    fn season(pending_competitions: u32) -> Season {
+        Season {
+            authority: Pubkey::default(),
+            season_id: 1,
+            usdc_mint: Pubkey::default(),
+            points_table: [0; LEADERBOARD_SIZE],
+            competition_count: pending_competitions,
+            pending_competitions,
+            champion: Pubkey::default(),
+            champion_points: 0,
+            settled: false,
+            prize_pool: 0,
+            prize_claimed: false,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn season_points_follow_shared_ranks() {
+        let mut season = season(1);
+        season.points_table[..3].copy_from_slice(&[25, 18, 15]);
+
+        let mut comp = competition(TieBreak::CoWinners);
+        comp.record(entry(1, 100, 0, 0));
+        comp.record(entry(2, 100, 0, 0));
+        comp.record(entry(3, 50, 0, 0));
+        let points = |k: u8| season.points_for(comp.rank_of(&Pubkey::new_from_array([k; 32])).unwrap());
+        // co-winners both take first-place points; the next trader is third
+        assert_eq!((points(1), points(2), points(3)), (25, 25, 15));
+        assert_eq!(season.points_for(LEADERBOARD_SIZE), 0);
+
+        assert!(!comp.season_fully_awarded());
+        comp.season_awarded = 0b011;
+        assert!(!comp.season_fully_awarded());
+        comp.season_awarded = 0b111;
+        assert!(comp.season_fully_awarded());
+    }
+
+    #[test]
+    fn season_releases_each_competition_once() {
+        let mut season = season(2);
+        let mut comp = competition(TieBreak::CoWinners);
+        comp.season_pending = true;
+        season.release(&mut comp).unwrap();
+        season.release(&mut comp).unwrap();
+        assert_eq!((season.pending_competitions, comp.season_pending), (1, false));
+
+        // never counted – nothing to release
+        let mut unlinked = competition(TieBreak::CoWinners);
+        season.release(&mut unlinked).unwrap();
+        assert_eq!(season.pending_competitions, 1);
+    }
+
+    #[test]
+    fn expected_score_matches_the_elo_curve() {
+        assert_eq!(expected_score_bps(1500, 1500), 5000);
+        assert_eq!(expected_score_bps(1500, 1900), 909);
+        assert_eq!(expected_score_bps(1900, 1500), 10_000 - 909);
+        // interpolated between the 0 and 25 point entries
+        assert_eq!(expected_score_bps(1500, 1512), 5000 - 359 * 12 / 25);
+        // clamped past 800 points
+        assert_eq!(expected_score_bps(100, 3000), 99);
+        let mut last = 10_000;
+        for gap in (-900..=900).step_by(7) {
+            let e = expected_score_bps(1500, (1500 + gap) as u32);
+            assert!(e <= last);
+            last = e;
+        }
+    }
+
+    #[test]
+    fn rating_delta_is_zero_sum_in_an_even_duel() {
+        assert_eq!(rating_delta(1500, 1500, 2, 2), 16);
+        assert_eq!(rating_delta(1500, 1500, 4, 2), -16);
+        // middle of a field of three at equal ratings
+        assert_eq!(rating_delta(1500, 1500, 4, 3), 0);
+        // the favourite gains little for winning, the underdog a lot
+        assert_eq!(rating_delta(1900, 1500, 2, 2), 3);
+        assert_eq!(rating_delta(1500, 1900, 2, 2), 29);
+        // a shared half rank: 2.5 of 4
+        assert_eq!(rating_delta(1500, 1500, 5, 4), 0);
+    }
+
+    #[test]
+    fn record_result_credits_wins_once() {
+        let mut profile = TraderProfile {
+            wallet: Pubkey::default(),
+            handle: String::new(),
+            rating: INITIAL_RATING,
+            peak_rating: INITIAL_RATING,
+            rated_competitions: 0,
+            competitions_entered: 0,
+            wins: 0,
+            best_profit: 0,
+            recent: [RecentResult::default(); RECENT_RESULTS],
+            recent_len: 0,
+            recent_head: 0,
+            bump: 0,
+        };
+        let comp = |k: u8| Pubkey::new_from_array([k; 32]);
+
+        profile.record_result(comp(1), -50, false, 10);
+        profile.record_result(comp(2), 70, true, 20);
+        profile.record_result(comp(2), 70, true, 30);
+        assert_eq!((profile.competitions_entered, profile.wins, profile.best_profit), (2, 1, 70));
+        assert!(!profile.recent[0].won && profile.recent[1].won);
+
+        // the ring buffer overwrites the oldest result
+        for k in 3..=RECENT_RESULTS as u8 + 1 {
+            profile.record_result(comp(k), 0, false, 40);
+        }
+        assert_eq!(profile.recent_len as usize, RECENT_RESULTS);
+        assert_eq!(profile.recent[0].competition, comp(RECENT_RESULTS as u8 + 1));
+        assert_eq!(profile.wins, 1);
+    }
+
+    #[test]
+    fn render_uri_fills_every_placeholder() {
+        let comp = Pubkey::new_from_array([1; 32]);
+        let winner = Pubkey::new_from_array([2; 32]);
+        assert_eq!(
+            render_uri("https://host/{competition}/{winner}.json?c={competition}", &comp, &winner),
+            format!("https://host/{comp}/{winner}.json?c={comp}")
+        );
+        assert_eq!(render_uri("ipfs://static", &comp, &winner), "ipfs://static");
+        assert_eq!(render_uri("{Winner}", &comp, &winner), "{Winner}");
+
+        // set_uri_template sizes templates against the widest base58 key
+        let widest = Pubkey::new_from_array([u8::MAX; 32]);
+        assert_eq!(render_uri("{winner}", &comp, &widest).len(), 44);
+    }
+
+    #[test]
+    fn remove_closes_the_gap_and_promotes_co_winners() {
+        let mut comp = competition(TieBreak::CoWinners);
+        comp.record(entry(1, 100, 0, 0));
+        comp.record(entry(2, 90, 0, 0));
+        comp.record(entry(3, 90, 0, 0));
+        comp.record(entry(4, 10, 0, 0));
+
+        comp.remove(&Pubkey::new_from_array([1; 32]));
+        assert_eq!(users(&comp), vec![2, 3, 4]);
+        assert_eq!((comp.winner_count, comp.winner_score), (2, 90));
+        assert_eq!(comp.rank_of(&Pubkey::new_from_array([4; 32])), Some(2));
+
+        // unknown users are ignored; the last one out empties the winners
+        comp.remove(&Pubkey::new_from_array([9; 32]));
+        for k in 2..=4 {
+            comp.remove(&Pubkey::new_from_array([k; 32]));
+        }
+        assert_eq!((comp.leaderboard_len, comp.winner_count), (0, 0));
+    }
+
+    #[test]
+    fn track_achievements_unlocks_streak_and_doubling() {
+        let mut pos = position(START);
+        pos.track_achievements(0);
+        assert_eq!(pos.achievements, ACHIEVEMENT_FIRST_TRADE);
+        assert_eq!(pos.profit_streak, 0);
+
+        for i in 1..PROFIT_STREAK_LEN as u128 {
+            let prev = pos.profit();
+            pos.current_value = START + i;
+            pos.track_achievements(prev);
+        }
+        assert_eq!(pos.achievements & ACHIEVEMENT_PROFIT_STREAK, 0);
+
+        // a flat trade resets the streak
+        let prev = pos.profit();
+        pos.track_achievements(prev);
+        assert_eq!(pos.profit_streak, 0);
+        for i in 0..PROFIT_STREAK_LEN as u128 {
+            let prev = pos.profit();
+            pos.current_value = START + 100 + i;
+            pos.track_achievements(prev);
+        }
+        assert_ne!(pos.achievements & ACHIEVEMENT_PROFIT_STREAK, 0);
+        assert_eq!(pos.achievements & ACHIEVEMENT_DOUBLED, 0);
+
+        let prev = pos.profit();
+        pos.current_value = 2 * START;
+        pos.track_achievements(prev);
+        assert_ne!(pos.achievements & ACHIEVEMENT_DOUBLED, 0);
+    }
+
+    #[test]
+    fn top_decile_rounds_the_field_up() {
+        let mut comp = competition(TieBreak::FewestTrades);
+        for k in 1..=4 {
+            comp.record(entry(k, 100 - k as i128, 0, 0));
+        }
+        let key = |k: u8| Pubkey::new_from_array([k; 32]);
+
+        comp.participant_count = 5;
+        assert!(comp.is_top_decile(&key(1)));
+        assert!(!comp.is_top_decile(&key(2)));
+
+        comp.participant_count = 21;
+        assert!(comp.is_top_decile(&key(3)));
+        assert!(!comp.is_top_decile(&key(4)));
+        assert!(!comp.is_top_decile(&key(9)));
+    }
+}
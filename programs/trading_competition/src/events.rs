@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::competition::Role;
 
 #[event]
 #[derive(Copy, Clone)]
@@ -12,6 +13,24 @@ pub struct TradeExecuted {
     pub new_current_value: u128,
     pub new_profit: i128,
     pub price_used: i64,
+    pub trade_count: u32,
+    pub buy_count: u32,
+    pub sell_count: u32,
+    pub total_volume: u128,
+    pub peak_value: u128,
+    pub max_drawdown: u128,
+    pub first_trade_at: i64,
+    pub last_trade_at: i64,
+    pub achievements: u8,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct CompetitionStarted {
+    pub competition: Pubkey,
+    pub participant_count: u32,
+    pub start_time: i64,
+    pub end_time: i64,
 }
 
 #[event]
@@ -31,4 +50,193 @@ pub struct UserUndelegated {
     pub competition: Pubkey,
     pub final_pnl: i128,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct PositionVerified {
+    pub user: Pubkey,
+    pub competition: Pubkey,
+    pub profit: i128,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct ChallengeOpened {
+    pub competition: Pubkey,
+    pub challenger: Pubkey,
+    pub bond: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct WinnerChallenged {
+    pub competition: Pubkey,
+    pub challenger: Pubkey,
+    pub upheld: bool,               // false = bond forfeited
+    pub previous_winner: Pubkey,
+    pub previous_score: i128,
+    pub new_winner: Pubkey,
+    pub new_score: i128,
+    pub bond: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct PositionSubmitted {
+    pub user: Pubkey,
+    pub competition: Pubkey,
+    pub profit: i128,
+    pub score: i128,
+    pub eligible: bool,
+    pub leader: Pubkey,
+    pub leader_score: i128,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct PrizeFunded {
+    pub competition: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub prize_pool: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct PrizeClaimed {
+    pub competition: Pubkey,
+    pub winner: Pubkey,
+    pub amount: u64,
+    pub co_winners: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct TeamJoined {
+    pub team: Pubkey,
+    pub competition: Pubkey,
+    pub member: Pubkey,
+    pub member_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct TeamMemberTallied {
+    pub team: Pubkey,
+    pub competition: Pubkey,
+    pub member: Pubkey,
+    pub member_profit: i128,
+    pub team_profit: i128,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct SeasonPointsAwarded {
+    pub season: Pubkey,
+    pub competition: Pubkey,
+    pub trader: Pubkey,
+    pub rank: u8,                   // 1-based
+    pub points: u32,
+    pub total_points: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct SeasonSettled {
+    pub season: Pubkey,
+    pub champion: Pubkey,
+    pub champion_points: u64,
+    pub competitions: u32,
+    pub timestamp: i64,
+}
+#[event]
+#[derive(Copy, Clone)]
+pub struct RatingUpdated {
+    pub trader: Pubkey,
+    pub competition: Pubkey,
+    pub rank: u32,                  // 1-based; unranked share the bottom ranks
+    pub field_size: u32,
+    pub old_rating: u32,
+    pub new_rating: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct TrophyMinted {
+    pub holder: Pubkey,
+    pub competition: Pubkey,
+    pub mint: Pubkey,
+    pub rank: u8,                   // 1-based; 1..=3 map to gold/silver/bronze
+    pub profit: i128,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct TrophyUpdated {
+    pub holder: Pubkey,
+    pub competition: Pubkey,
+    pub mint: Pubkey,
+    pub rank: u8,                   // 1-based
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct ResultOverturned {
+    pub competition: Pubkey,
+    pub trader: Pubkey,
+    pub previous_rank: u16,         // 1-based, 0 = was not ranked
+    pub new_winner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct TrophyRevoked {
+    pub holder: Pubkey,
+    pub competition: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct AchievementClaimed {
+    pub user: Pubkey,
+    pub competition: Pubkey,
+    pub achievement: u8,            // bit index, see ACHIEVEMENT_*
+    pub badge_mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct ParticipationBadgeClaimed {
+    pub user: Pubkey,
+    pub competition: Pubkey,
+    pub rank: u16,                  // 1-based, 0 = outside the leaderboard
+    pub profit: i128,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Copy, Clone)]
+pub struct RoleUpdated {
+    pub competition: Pubkey,
+    pub role: Role,
+    pub previous: Pubkey,
+    pub holder: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+
+#[derive(Accounts)]
+pub struct AddCompetitionToSeason<'info> {
+    #[account(
+        mut,
+        constraint = !season.settled @ CompetitionError::SeasonSettled
+    )]
+    pub season: Account<'info, Season>,
+
+    /// Season owner approves the link
+    #[account(address = season.authority @ CompetitionError::Unauthorized)]
+    pub season_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = competition.phase != CompetitionPhase::Settled @ CompetitionError::NotActive,
+        constraint = competition.season == Pubkey::default() @ CompetitionError::AlreadyInSeason
+    )]
+    pub competition: Account<'info, Competition>,
+
+    /// Competition owner opts in
+    #[account(address = competition.authority @ CompetitionError::Unauthorized)]
+    pub competition_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AddCompetitionToSeason>) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let comp = &mut ctx.accounts.competition;
+
+    comp.season = season.key();
+    comp.season_pending = true;
+    season.competition_count = season
+        .competition_count
+        .checked_add(1)
+        .ok_or(CompetitionError::CalculationError)?;
+    season.pending_competitions = season
+        .pending_competitions
+        .checked_add(1)
+        .ok_or(CompetitionError::CalculationError)?;
+
+    msg!("Competition {} joined season {}", comp.key(), season.season_id);
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::SeasonPointsAwarded;
+
+#[derive(Accounts)]
+#[instruction(trader: Pubkey)]
+pub struct AwardSeasonPoints<'info> {
+    #[account(
+        mut,
+        constraint = !season.settled @ CompetitionError::SeasonSettled
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        mut,
+        constraint = competition.season == season.key() @ CompetitionError::WrongSeason,
+        constraint = competition.phase == CompetitionPhase::Settled @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + SeasonStanding::INIT_SPACE,
+        seeds = [b"season_standing", season.key().as_ref(), trader.as_ref()],
+        bump
+    )]
+    pub standing: Account<'info, SeasonStanding>,
+
+    /// Permissionless crank – pays for new standings
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AwardSeasonPoints>, trader: Pubkey) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let comp = &mut ctx.accounts.competition;
+    let standing = &mut ctx.accounts.standing;
+    require!(
+        Clock::get()?.unix_timestamp >= comp.review_deadline,
+        CompetitionError::UnderReview
+    );
+
+    // ---- Final rank from the settled leaderboard ----
+    let (slot, rank) = comp
+        .slot_of(&trader)
+        .zip(comp.rank_of(&trader))
+        .ok_or(CompetitionError::NotEligible)?;
+    require!(comp.season_awarded & (1 << slot) == 0, CompetitionError::AlreadyAwarded);
+    let points = season.points_for(rank);
+    comp.season_awarded |= 1 << slot;
+    if comp.season_fully_awarded() {
+        season.release(comp)?;
+    }
+
+    if standing.trader == Pubkey::default() {
+        standing.season = season.key();
+        standing.trader = trader;
+        standing.bump = ctx.bumps.standing;
+    }
+    standing.points = standing
+        .points
+        .checked_add(points as u64)
+        .ok_or(CompetitionError::CalculationError)?;
+    standing.competitions_scored += 1;
+
+    // ---- Track the champion as points come in ----
+    if season.champion == Pubkey::default()
+        || standing.points > season.champion_points
+        || (standing.points == season.champion_points && trader < season.champion)
+    {
+        season.champion = trader;
+        season.champion_points = standing.points;
+    }
+
+    emit!(SeasonPointsAwarded {
+        season: season.key(),
+        competition: comp.key(),
+        trader,
+        rank: rank as u8 + 1,
+        points,
+        total_points: standing.points,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::competition::*;
+use crate::events::ChallengeOpened;
+use state_root::verify_proof;
+
+#[derive(Accounts)]
+pub struct ChallengeWinner<'info> {
+    #[account(
+        mut,
+        constraint = competition.phase == CompetitionPhase::Finalizing @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    #[account(
+        mut,
+        has_one = competition,
+        has_one = user,
+        seeds = [b"position", competition.key().as_ref(), user.key().as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + Challenge::INIT_SPACE,
+        seeds = [b"challenge", competition.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens the challenge and escrows the bond; `resolve_challenge` decides it.
+pub fn handler(ctx: Context<ChallengeWinner>, proof: Vec<[u8; 32]>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let comp = &mut ctx.accounts.competition;
+    let pos = &mut ctx.accounts.position;
+
+    require!(now < comp.challenge_deadline, CompetitionError::ChallengeWindowClosed);
+    require!(comp.state_root != [0u8; 32], CompetitionError::NoStateRoot);
+
+    // ---- Prove the challenger's position is part of the committed state ----
+    if !pos.verified {
+        require!(
+            verify_proof(&proof, &comp.state_root, pos.leaf()),
+            CompetitionError::InvalidProof
+        );
+        pos.verified = true;
+    }
+
+    // ---- Escrow bond in the challenge PDA ----
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.challenge.to_account_info(),
+            },
+        ),
+        CHALLENGE_BOND_LAMPORTS,
+    )?;
+
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.competition = comp.key();
+    challenge.challenger = pos.user;
+    challenge.bond = CHALLENGE_BOND_LAMPORTS;
+    challenge.opened_at = now;
+    challenge.committed_head = (comp.leaderboard_len > 0).then(|| comp.leaderboard[0]);
+    challenge.bump = ctx.bumps.challenge;
+
+    comp.open_challenges = comp
+        .open_challenges
+        .checked_add(1)
+        .ok_or(CompetitionError::CalculationError)?;
+
+    emit!(ChallengeOpened {
+        competition: comp.key(),
+        challenger: pos.user,
+        bond: CHALLENGE_BOND_LAMPORTS,
+        timestamp: now,
+    });
+
+    msg!("Challenge by {} opened – bond {} escrowed", pos.user, CHALLENGE_BOND_LAMPORTS);
+    Ok(())
+}
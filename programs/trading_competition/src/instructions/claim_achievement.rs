@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::AchievementClaimed;
+use crate::instructions::mint_soulbound_trophy::{create_soulbound_mint, MintMetadata, SoulboundMintAccounts};
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken};
+use anchor_spl::token_interface::{self, MintTo, Token2022};
+
+#[derive(Accounts)]
+#[instruction(achievement: u8)]
+pub struct ClaimAchievement<'info> {
+    #[account(
+        constraint = competition.phase == CompetitionPhase::Settled @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    #[account(
+        mut,
+        has_one = competition,
+        has_one = user,
+        constraint = !position.overturned @ CompetitionError::AlreadyOverturned,
+        seeds = [b"position", competition.key().as_ref(), user.key().as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: PDA – mint and metadata authority of every badge mint
+    #[account(seeds = [b"badge_authority"], bump)]
+    pub badge_authority: UncheckedAccount<'info>,
+
+    /// CHECK: One non-transferable Token-2022 mint per achievement, shared by
+    /// all competitions; created by the first claim
+    #[account(
+        mut,
+        seeds = [b"badge_mint".as_ref(), &[achievement]],
+        bump
+    )]
+    pub badge_mint: UncheckedAccount<'info>,
+
+    /// CHECK: User's Token-2022 ATA for the badge, created if missing
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &user.key(),
+            &badge_mint.key(),
+            &token_program.key()
+        )
+    )]
+    pub badge_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimAchievement>, achievement: u8) -> Result<()> {
+    require!(achievement < ACHIEVEMENT_COUNT, CompetitionError::UnknownAchievement);
+    let comp = &ctx.accounts.competition;
+    let pos = &mut ctx.accounts.position;
+    let bit = 1u8 << achievement;
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let mint = ctx.accounts.badge_mint.to_account_info();
+    let authority_seeds: &[&[&[u8]]] = &[&[b"badge_authority", &[ctx.bumps.badge_authority]]];
+
+    // ---- Settlement achievements are only known now ----
+    if comp.is_top_decile(&pos.user) {
+        pos.achievements |= ACHIEVEMENT_TOP_DECILE;
+    }
+
+    require!(pos.achievements & bit != 0, CompetitionError::AchievementLocked);
+    require!(pos.achievements_claimed & bit == 0, CompetitionError::AlreadyClaimed);
+    pos.achievements_claimed |= bit;
+
+    if mint.data_is_empty() {
+        create_soulbound_mint(
+            &SoulboundMintAccounts {
+                payer: ctx.accounts.user.to_account_info(),
+                mint: mint.clone(),
+                authority: ctx.accounts.badge_authority.to_account_info(),
+                token_program: token_program.clone(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &[&[b"badge_mint", &[achievement], &[ctx.bumps.badge_mint]]],
+            authority_seeds,
+            MintMetadata {
+                name: ACHIEVEMENT_NAMES[achievement as usize].to_string(),
+                symbol: ACHIEVEMENT_SYMBOL.to_string(),
+                uri: String::new(),
+                attributes: vec![("achievement".to_string(), achievement.to_string())],
+            },
+        )?;
+    }
+
+    associated_token::create_idempotent(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: ctx.accounts.user.to_account_info(),
+            associated_token: ctx.accounts.badge_account.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+            mint: mint.clone(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: token_program.clone(),
+        },
+    ))?;
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            token_program,
+            MintTo {
+                mint: mint.clone(),
+                to: ctx.accounts.badge_account.to_account_info(),
+                authority: ctx.accounts.badge_authority.to_account_info(),
+            },
+            authority_seeds,
+        ),
+        1,
+    )?;
+
+    emit!(AchievementClaimed {
+        user: pos.user,
+        competition: comp.key(),
+        achievement,
+        badge_mint: mint.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
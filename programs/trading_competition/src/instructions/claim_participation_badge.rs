@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::ParticipationBadgeClaimed;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, MintTo, Token2022, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ClaimParticipationBadge<'info> {
+    #[account(
+        constraint = competition.phase == CompetitionPhase::Settled @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    /// Only loads once undelegated – the ER copy is owned by the delegation program
+    #[account(
+        has_one = competition,
+        has_one = user,
+        constraint = position.trade_count > 0
+            && position.is_eligible(&competition.config.eligibility) @ CompetitionError::NotEligible,
+        constraint = !position.overturned @ CompetitionError::AlreadyOverturned,
+        seeds = [b"position", competition.key().as_ref(), user.key().as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + ParticipationRecord::INIT_SPACE,
+        seeds = [b"participation", competition.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub record: Account<'info, ParticipationRecord>,
+
+    #[account(
+        mut,
+        mint::token_program = token_program,
+        seeds = [b"participation_mint", competition.key().as_ref()],
+        bump
+    )]
+    pub participation_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = participation_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub badge_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimParticipationBadge>) -> Result<()> {
+    let comp = &ctx.accounts.competition;
+    let pos = &ctx.accounts.position;
+    let now = Clock::get()?.unix_timestamp;
+
+    let record = &mut ctx.accounts.record;
+    record.competition = comp.key();
+    record.user = pos.user;
+    record.rank = comp.rank_of(&pos.user).map_or(0, |r| r as u16 + 1);
+    record.profit = pos.profit();
+    record.claimed_at = now;
+    record.bump = ctx.bumps.record;
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.participation_mint.to_account_info(),
+                to: ctx.accounts.badge_account.to_account_info(),
+                authority: ctx.accounts.competition.to_account_info(),
+            },
+            &[&[b"competition", comp.authority.as_ref(), &[comp.bump]]],
+        ),
+        1,
+    )?;
+
+    emit!(ParticipationBadgeClaimed {
+        user: pos.user,
+        competition: comp.key(),
+        rank: record.rank,
+        profit: record.profit,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount, Transfer};
+use crate::competition::*;
+use crate::events::PrizeClaimed;
+
+#[derive(Accounts)]
+pub struct ClaimPrize<'info> {
+    #[account(
+        mut,
+        has_one = usdc_mint,
+        constraint = competition.phase == CompetitionPhase::Settled @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = competition,
+        seeds = [b"prize_vault", competition.key().as_ref()],
+        bump
+    )]
+    pub prize_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        has_one = competition,
+        seeds = [b"position", competition.key().as_ref(), winner.key().as_ref()],
+        bump = winner_position.bump,
+        constraint = winner_position.is_eligible(&competition.config.eligibility) @ CompetitionError::NotEligible
+    )]
+    pub winner_position: Account<'info, Position>,
+
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = winner
+    )]
+    pub winner_usdc_ata: Account<'info, TokenAccount>,
+
+    pub winner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimPrize>) -> Result<()> {
+    let comp = &ctx.accounts.competition;
+    let winner = ctx.accounts.winner.key();
+    require!(
+        Clock::get()?.unix_timestamp >= comp.review_deadline,
+        CompetitionError::UnderReview
+    );
+
+    // ---- Co-winners split the pool evenly ----
+    let slot = comp
+        .winners()
+        .iter()
+        .position(|e| e.user == winner)
+        .ok_or(CompetitionError::NotWinner)?;
+    require!(comp.prize_claimed & (1 << slot) == 0, CompetitionError::AlreadyClaimed);
+    let amount = comp.prize_share();
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"competition",
+        comp.authority.as_ref(),
+        &[comp.bump]
+    ]];
+
+    anchor_spl::token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.prize_vault.to_account_info(),
+                to: ctx.accounts.winner_usdc_ata.to_account_info(),
+                authority: ctx.accounts.competition.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    let comp = &mut ctx.accounts.competition;
+    comp.prize_claimed |= 1 << slot;
+
+    emit!(PrizeClaimed {
+        competition: comp.key(),
+        winner,
+        amount,
+        co_winners: comp.winner_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount, Transfer};
+use crate::competition::*;
+
+#[derive(Accounts)]
+pub struct ClaimSeasonPrize<'info> {
+    #[account(
+        mut,
+        has_one = usdc_mint,
+        constraint = season.settled @ CompetitionError::SeasonNotSettled,
+        constraint = season.champion == champion.key() @ CompetitionError::NotWinner,
+        constraint = !season.prize_claimed @ CompetitionError::AlreadyClaimed
+    )]
+    pub season: Account<'info, Season>,
+
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = season,
+        seeds = [b"season_vault", season.key().as_ref()],
+        bump
+    )]
+    pub season_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = champion
+    )]
+    pub champion_usdc_ata: Account<'info, TokenAccount>,
+
+    pub champion: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimSeasonPrize>) -> Result<()> {
+    let season = &ctx.accounts.season;
+    let amount = season.prize_pool;
+
+    let season_id = season.season_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"season",
+        season.authority.as_ref(),
+        &season_id,
+        &[season.bump]
+    ]];
+
+    anchor_spl::token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.season_vault.to_account_info(),
+                to: ctx.accounts.champion_usdc_ata.to_account_info(),
+                authority: ctx.accounts.season.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    ctx.accounts.season.prize_claimed = true;
+
+    msg!("Season champion {} claimed {}", ctx.accounts.champion.key(), amount);
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::instructions::mint_soulbound_trophy::{mint_soulbound_trophy, SoulboundTrophyAccounts};
+use anchor_spl::associated_token::{get_associated_token_address_with_program_id, AssociatedToken};
+use anchor_spl::token_interface::Token2022;
+
+#[derive(Accounts)]
+pub struct ClaimSoulboundTrophy<'info> {
+    #[account(
+        constraint = competition.phase == CompetitionPhase::Settled @ CompetitionError::NotActive,
+        constraint = competition.config.trophy == TrophyKind::Soulbound @ CompetitionError::WrongTrophyKind
+    )]
+    pub competition: Account<'info, Competition>,
+
+    /// The winner claims and pays for their own trophy
+    #[account(mut, address = winner_position.user @ CompetitionError::Unauthorized)]
+    pub winner: Signer<'info>,
+
+    #[account(
+        has_one = competition,
+        constraint = competition.rank_of(&winner_position.user)
+            .and_then(TrophyTier::from_rank)
+            .is_some() @ CompetitionError::NotWinner,
+        constraint = winner_position.is_eligible(&competition.config.eligibility) @ CompetitionError::NotEligible,
+        seeds = [b"position", competition.key().as_ref(), winner_position.user.as_ref()],
+        bump = winner_position.bump
+    )]
+    pub winner_position: Account<'info, Position>,
+
+    /// CHECK: Created in the handler – NonTransferable + MetadataPointer extensions
+    #[account(
+        mut,
+        seeds = [b"trophy_mint", competition.key().as_ref(), winner_position.user.as_ref()],
+        bump
+    )]
+    pub trophy_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Winner's Token-2022 ATA, created in the handler
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &winner.key(),
+            &trophy_mint.key(),
+            &token_program.key()
+        )
+    )]
+    pub trophy_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimSoulboundTrophy>) -> Result<()> {
+    let accounts = SoulboundTrophyAccounts {
+        payer: ctx.accounts.winner.to_account_info(),
+        winner_wallet: ctx.accounts.winner.to_account_info(),
+        trophy_mint: ctx.accounts.trophy_mint.to_account_info(),
+        trophy_account: ctx.accounts.trophy_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+    mint_soulbound_trophy(
+        accounts,
+        &ctx.accounts.competition,
+        &ctx.accounts.winner_position,
+        ctx.bumps.trophy_mint,
+    )
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::instructions::mint_winner_nft::{mint_winner_nft, WinnerNftAccounts};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ClaimWinnerNft<'info> {
+    #[account(
+        mut,
+        constraint = competition.phase == CompetitionPhase::Settled @ CompetitionError::NotActive,
+        constraint = competition.config.trophy == TrophyKind::Collection @ CompetitionError::WrongTrophyKind
+    )]
+    pub competition: Account<'info, Competition>,
+
+    /// The winner claims and pays for their own trophy
+    #[account(mut, address = winner_position.user @ CompetitionError::Unauthorized)]
+    pub winner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = competition,
+        constraint = competition.rank_of(&winner_position.user)
+            .and_then(TrophyTier::from_rank)
+            .is_some() @ CompetitionError::NotWinner,
+        constraint = winner_position.is_eligible(&competition.config.eligibility) @ CompetitionError::NotEligible,
+        seeds = [b"position", competition.key().as_ref(), winner_position.user.as_ref()],
+        bump = winner_position.bump
+    )]
+    pub winner_position: Account<'info, Position>,
+
+    #[account(
+        init,
+        payer = winner,
+        mint::decimals = 0,
+        mint::authority = competition,
+        seeds = [b"nft_mint", competition.key().as_ref(), winner_position.user.as_ref()],
+        bump
+    )]
+    pub nft_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = winner,
+        token::mint = nft_mint,
+        token::authority = winner
+    )]
+    pub nft_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.to_bytes().as_ref(),
+            nft_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program
+    )]
+    /// CHECK: Metaplex metadata PDA
+    pub metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: PDA – signs the collection verification
+    #[account(seeds = [b"collection_authority", competition.authority.as_ref()], bump)]
+    pub collection_authority: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"collection_mint", competition.authority.as_ref()], bump)]
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.to_bytes().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program
+    )]
+    /// CHECK: Metaplex metadata PDA of the collection
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.to_bytes().as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program
+    )]
+    /// CHECK: Metaplex master edition PDA of the collection
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<ClaimWinnerNft>) -> Result<()> {
+    let accounts = WinnerNftAccounts {
+        payer: ctx.accounts.winner.to_account_info(),
+        nft_mint: ctx.accounts.nft_mint.to_account_info(),
+        nft_account: ctx.accounts.nft_account.to_account_info(),
+        metadata_account: ctx.accounts.metadata_account.to_account_info(),
+        collection_authority: ctx.accounts.collection_authority.to_account_info(),
+        collection_mint: ctx.accounts.collection_mint.to_account_info(),
+        collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+        collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    mint_winner_nft(
+        accounts,
+        &ctx.accounts.competition,
+        &ctx.accounts.winner_position,
+        ctx.bumps.collection_authority,
+    )
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::TeamJoined;
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateTeam<'info> {
+    #[account(
+        mut,
+        constraint = matches!(competition.phase, CompetitionPhase::Upcoming | CompetitionPhase::Active)
+            @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    #[account(
+        init,
+        payer = captain,
+        space = 8 + Team::INIT_SPACE,
+        seeds = [b"team", competition.key().as_ref(), captain.key().as_ref()],
+        bump
+    )]
+    pub team: Account<'info, Team>,
+
+    #[account(
+        init,
+        payer = captain,
+        space = 8 + TeamMember::INIT_SPACE,
+        seeds = [b"team_member", competition.key().as_ref(), captain.key().as_ref()],
+        bump
+    )]
+    pub membership: Account<'info, TeamMember>,
+
+    /// CHECK: captain's Position PDA – may sit in the ER, only existence is checked
+    #[account(
+        seeds = [b"position", competition.key().as_ref(), captain.key().as_ref()],
+        bump
+    )]
+    pub captain_position: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub captain: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateTeam>, name: String) -> Result<()> {
+    let comp = &mut ctx.accounts.competition;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(comp.config.max_team_size > 0, CompetitionError::TeamsDisabled);
+    // Open throughout Upcoming, whatever the provisional end_time
+    require!(
+        comp.phase == CompetitionPhase::Upcoming || now < comp.end_time,
+        CompetitionError::NotActive
+    );
+    require!(name.len() <= MAX_TEAM_NAME_LEN, CompetitionError::NameTooLong);
+    require!(
+        !ctx.accounts.captain_position.data_is_empty(),
+        CompetitionError::NotRegistered
+    );
+
+    // Every member must be tallied before the competition settles
+    comp.untallied_members = comp
+        .untallied_members
+        .checked_add(1)
+        .ok_or(CompetitionError::CalculationError)?;
+
+    let team = &mut ctx.accounts.team;
+    team.competition = comp.key();
+    team.captain = ctx.accounts.captain.key();
+    team.name = name;
+    team.members = [Pubkey::default(); MAX_TEAM_SIZE];
+    team.members[0] = team.captain;
+    team.member_count = 1;
+    team.tallied = 0;
+    team.total_profit = 0;
+    team.bump = ctx.bumps.team;
+
+    let membership = &mut ctx.accounts.membership;
+    membership.team = team.key();
+    membership.user = team.captain;
+    membership.bump = ctx.bumps.membership;
+
+    emit!(TeamJoined {
+        team: team.key(),
+        competition: comp.key(),
+        member: team.captain,
+        member_count: team.member_count,
+        timestamp: now,
+    });
+
+    Ok(())
+}
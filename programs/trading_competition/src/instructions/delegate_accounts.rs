@@ -1,84 +1,171 @@
 use anchor_lang::prelude::*;
 use crate::competition::*;
-use anchor_spl::token::{Token, TokenAccount};
-
-use ephemeral_rollups_sdk::cpi::*;
-use ephemeral_rollups_sdk::consts::DELEGATION_PROGRAM_ID;
-// use ephemeral_rollups_sdk::delegate_args::*;
+use state_root::{allowlist_leaf, verify_proof};
+use anchor_spl::token::TokenAccount;
+use mpl_token_metadata::accounts::Metadata;
 
 #[derive(Accounts)]
 pub struct DelegateAccounts<'info> {
     #[account(
         mut,
-        has_one = authority,
         has_one = er_instance,
-        constraint = competition.phase == CompetitionPhase::Upcoming @ CompetitionError::NotActive
+        constraint = matches!(competition.phase, CompetitionPhase::Upcoming | CompetitionPhase::Active)
+            @ CompetitionError::NotActive
     )]
     pub competition: Account<'info, Competition>,
 
     #[account(mut)]
     pub user: Signer<'info>,
 
-    #[account(
-        mut,
-        token::mint = competition.usdc_mint,
-        token::authority = user
-    )]
-    pub user_usdc_ata: Account<'info, TokenAccount>,
-
     #[account(
         init,
         payer = user,
-        space = 8 + 32*3 + 16*3 + 1,
+        space = 8 + Position::INIT_SPACE,
         seeds = [b"position", competition.key().as_ref(), user.key().as_ref()],
         bump
     )]
     pub position: Account<'info, Position>,
 
-    /// CHECK: MagicBlock delegation program
-    #[account(address = DELEGATION_PROGRAM_ID)]
-    pub delegation_program: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + TraderProfile::INIT_SPACE,
+        seeds = [b"trader_profile", user.key().as_ref()],
+        bump
+    )]
+    pub trader_profile: Account<'info, TraderProfile>,
+
+    /// Holding that satisfies `competition.config.entry_gate`
+    #[account(token::authority = user)]
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Metaplex metadata of the gate NFT – validated in handler
+    pub gate_nft_metadata: Option<UncheckedAccount<'info>>,
 
     /// CHECK: Must equal competition.er_instance
     #[account(address = competition.er_instance)]
     pub er_instance: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<DelegateAccounts>, _er_instance: Pubkey) -> Result<()> {
-    let comp = &ctx.accounts.competition;
+pub fn handler(
+    ctx: Context<DelegateAccounts>,
+    _er_instance: Pubkey,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let comp = &mut ctx.accounts.competition;
     let pos = &mut ctx.accounts.position;
 
+    // Late entry is allowed until trading ends
+    require!(
+        comp.phase == CompetitionPhase::Upcoming || Clock::get()?.unix_timestamp < comp.end_time,
+        CompetitionError::NotActive
+    );
+
+    // ---- Registration gate ----
+    let max = comp.config.max_participants;
+    require!(max == 0 || comp.participant_count < max, CompetitionError::CompetitionFull);
+    comp.participant_count = comp
+        .participant_count
+        .checked_add(1)
+        .ok_or(CompetitionError::CalculationError)?;
+
+    // ---- Entry gate ----
+    match comp.config.entry_gate {
+        EntryGate::Open => {}
+        EntryGate::TokenBalance { mint, min_amount } => {
+            let holding = ctx.accounts.gate_token_account.as_ref()
+                .ok_or(CompetitionError::EntryGateNotMet)?;
+            require!(
+                holding.mint == mint && holding.amount >= min_amount,
+                CompetitionError::EntryGateNotMet
+            );
+        }
+        EntryGate::Collection { collection } => {
+            let holding = ctx.accounts.gate_token_account.as_ref()
+                .ok_or(CompetitionError::EntryGateNotMet)?;
+            let metadata = ctx.accounts.gate_nft_metadata.as_ref()
+                .ok_or(CompetitionError::EntryGateNotMet)?;
+            require!(holding.amount >= 1, CompetitionError::EntryGateNotMet);
+            require!(
+                *metadata.owner == mpl_token_metadata::ID
+                    && metadata.key() == Metadata::find_pda(&holding.mint).0,
+                CompetitionError::EntryGateNotMet
+            );
+            let nft = Metadata::safe_deserialize(&metadata.try_borrow_data()?)
+                .map_err(|_| error!(CompetitionError::EntryGateNotMet))?;
+            require!(
+                nft.collection.is_some_and(|c| c.verified && c.key == collection),
+                CompetitionError::EntryGateNotMet
+            );
+        }
+    }
+    if comp.registration_deadline != 0 {
+        require!(
+            Clock::get()?.unix_timestamp < comp.registration_deadline,
+            CompetitionError::RegistrationClosed
+        );
+    }
+    if comp.allowlist_root != [0u8; 32] {
+        require!(
+            verify_proof(&proof, &comp.allowlist_root, allowlist_leaf(&ctx.accounts.user.key().to_bytes())),
+            CompetitionError::NotAllowlisted
+        );
+    }
+
+    // ---- Rating snapshot (first competition creates the profile) ----
+    let profile = &mut ctx.accounts.trader_profile;
+    if profile.wallet == Pubkey::default() {
+        profile.wallet = ctx.accounts.user.key();
+        profile.handle = String::new();
+        profile.rating = INITIAL_RATING;
+        profile.peak_rating = INITIAL_RATING;
+        profile.rated_competitions = 0;
+        profile.competitions_entered = 0;
+        profile.wins = 0;
+        profile.best_profit = 0;
+        profile.recent_len = 0;
+        profile.recent_head = 0;
+        profile.bump = ctx.bumps.trader_profile;
+    }
+    comp.field_rating_sum = comp
+        .field_rating_sum
+        .checked_add(profile.rating as u64)
+        .ok_or(CompetitionError::CalculationError)?;
+
     // initialise synthetic balance (demo: 1 M USDC)
     pos.competition = comp.key();
     pos.user = ctx.accounts.user.key();
-    pos.usdc_ata = ctx.accounts.user_usdc_ata.key();
     pos.usdc_balance = 1_000_000_000_000u128; // 1 M * 10^6
     pos.initial_value = pos.usdc_balance;
     pos.current_value = pos.usdc_balance;
+    pos.verified = false;
+    pos.submitted = false;
+    pos.trade_count = 0;
+    pos.buy_count = 0;
+    pos.sell_count = 0;
+    pos.total_volume = 0;
+    pos.first_trade_at = 0;
+    pos.last_trade_at = 0;
+    pos.active_days = 0;
+    pos.last_active_day = 0;
+    pos.peak_value = pos.current_value;
+    pos.max_drawdown = 0;
+    pos.sample_count = 0;
+    pos.last_sample_value = pos.current_value;
+    pos.last_sample_at = 0;
+    pos.return_sum = 0;
+    pos.return_sq_sum = 0;
+    pos.achievements = 0;
+    pos.achievements_claimed = 0;
+    pos.profit_streak = 0;
+    pos.entry_rating = profile.rating;
+    pos.rating_applied = false;
+    pos.overturned = false;
     pos.bump = ctx.bumps.position;
 
-    // CPI to delegate
-    let cpi_prog = ctx.accounts.delegation_program.to_account_info();
-    let cpi_accounts = DelegateAccount {
-        accounts_to_delegate: vec![
-            ctx.accounts.position.to_account_info(),
-            ctx.accounts.user_usdc_ata.to_account_info(),
-        ],
-        er_instance: ctx.accounts.er_instance.to_account_info(),
-        payer: ctx.accounts.user.to_account_info(),
-    };
-    let data = DelegateInstructionData {
-        er_instance: comp.er_instance,
-        payer: ctx.accounts.user.key(),
-        accounts_to_delegate: vec![pos.key(), ctx.accounts.user_usdc_ata.key()],
-    };
-
-    delegate_account(CpiContext::new(cpi_prog, cpi_accounts), data)?;
-
-    msg!("User {} delegated to ER {}", ctx.accounts.user.key(), comp.er_instance);
+    msg!("User {} registered for {}", ctx.accounts.user.key(), comp.key());
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use ephemeral_rollups_sdk::anchor::delegate;
+use ephemeral_rollups_sdk::cpi::DelegateConfig;
+
+/// Second half of registration: hands the Position to the ER validator.
+/// Sent right after `delegate_accounts`, usually in the same transaction.
+#[delegate]
+#[derive(Accounts)]
+pub struct DelegatePosition<'info> {
+    #[account(
+        has_one = er_instance,
+        constraint = matches!(competition.phase, CompetitionPhase::Upcoming | CompetitionPhase::Active)
+            @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Position PDA created by delegate_accounts; still owned by this program
+    #[account(
+        mut, del,
+        owner = crate::ID @ CompetitionError::NotRegistered,
+        seeds = [b"position", competition.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub position: AccountInfo<'info>,
+
+    /// CHECK: Must equal competition.er_instance
+    #[account(address = competition.er_instance)]
+    pub er_instance: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<DelegatePosition>) -> Result<()> {
+    let comp_key = ctx.accounts.competition.key();
+    let user_key = ctx.accounts.user.key();
+    let comp = &ctx.accounts.competition;
+    require!(
+        comp.phase == CompetitionPhase::Upcoming || Clock::get()?.unix_timestamp < comp.end_time,
+        CompetitionError::NotActive
+    );
+
+    ctx.accounts.delegate_position(
+        &ctx.accounts.user,
+        &[b"position", comp_key.as_ref(), user_key.as_ref()],
+        DelegateConfig {
+            validator: Some(ctx.accounts.er_instance.key()),
+            ..Default::default()
+        },
+    )?;
+
+    msg!("User {} delegated to ER {}", user_key, ctx.accounts.er_instance.key());
+    Ok(())
+}
@@ -3,31 +3,41 @@ use crate::competition::*;
 use hex;
 
 #[derive(Accounts)]
+#[instruction(er_instance: Pubkey)]
 pub struct FinalCommit<'info> {
     #[account(
         mut,
-        has_one = authority,
+        constraint = finalizer.key() == competition.roles.finalizer @ CompetitionError::Unauthorized,
         has_one = er_instance,
         constraint = competition.phase == CompetitionPhase::Active @ CompetitionError::NotActive
     )]
     pub competition: Account<'info, Competition>,
 
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub finalizer: Signer<'info>,
 
     /// CHECK: Must be competition.er_instance
     #[account(address = competition.er_instance @ CompetitionError::Unauthorized)]
     pub er_instance: UncheckedAccount<'info>,
 
+    /// Undelegated winner position – its score is computed here, never taken on trust
+    #[account(
+        has_one = competition,
+        seeds = [b"position", competition.key().as_ref(), winner_position.user.as_ref()],
+        bump = winner_position.bump
+    )]
+    pub winner_position: Account<'info, Position>,
+
     pub clock: Sysvar<'info, Clock>,
 }
 
-pub fn handler(
-    ctx: Context<FinalCommit>,
+/// `remaining_accounts`: up to `LEADERBOARD_SIZE - 1` undelegated runner-up
+/// positions, any order. They are scored the same way as the winner, so
+/// silver/bronze trophies and season points exist in this mode too.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FinalCommit<'info>>,
     er_instance: Pubkey,
     state_root: [u8; 32],
-    winner_pubkey: Pubkey,
-    winner_profit: i128,
 ) -> Result<()> {
     let comp = &mut ctx.accounts.competition;
     let now = ctx.accounts.clock.unix_timestamp;
@@ -35,10 +45,42 @@ pub fn handler(
     // sanity checks
     require!(er_instance == comp.er_instance, CompetitionError::Unauthorized);
     require!(now >= comp.end_time, CompetitionError::NotEnded);
+    require!(
+        comp.config.finalization == FinalizationMode::Authority,
+        CompetitionError::WrongFinalizationMode
+    );
+    let winner_position = &ctx.accounts.winner_position;
+    require!(
+        winner_position.is_eligible(&comp.config.eligibility),
+        CompetitionError::NotEligible
+    );
+
+    let entry = winner_position.entry(&comp.config);
 
     comp.state_root = state_root;
-    comp.winner = winner_pubkey;
-    comp.winner_profit = winner_profit;
+    comp.leaderboard_len = 0;
+    comp.record(entry);
+
+    require!(
+        ctx.remaining_accounts.len() < LEADERBOARD_SIZE,
+        CompetitionError::InvalidRunnerUp
+    );
+    for info in ctx.remaining_accounts {
+        let runner_up = Account::<Position>::try_from(info)?;
+        require_keys_eq!(runner_up.competition, comp.key(), CompetitionError::InvalidRunnerUp);
+        require!(
+            comp.slot_of(&runner_up.user).is_none(),
+            CompetitionError::InvalidRunnerUp
+        );
+        require!(
+            runner_up.is_eligible(&comp.config.eligibility),
+            CompetitionError::NotEligible
+        );
+        let entry = runner_up.entry(&comp.config);
+        comp.record(entry);
+    }
+    require!(comp.is_winner(&winner_position.user), CompetitionError::InvalidRunnerUp);
+
     comp.phase = CompetitionPhase::Finalizing;
     comp.challenge_deadline = now + 300; // 5 min window
 
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+
+#[derive(Accounts)]
+pub struct FinalizeWinner<'info> {
+    #[account(
+        mut,
+        constraint = competition.phase == CompetitionPhase::Active @ CompetitionError::NotActive,
+        constraint = competition.config.finalization == FinalizationMode::OnChain
+            @ CompetitionError::WrongFinalizationMode
+    )]
+    pub competition: Account<'info, Competition>,
+}
+
+/// Permissionless crank – closes submissions and locks in the on-chain leader.
+pub fn handler(ctx: Context<FinalizeWinner>) -> Result<()> {
+    let comp = &mut ctx.accounts.competition;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(now >= comp.end_time + SUBMISSION_WINDOW, CompetitionError::NotEnded);
+    // Ineligible submissions are counted but never ranked
+    require!(comp.leaderboard_len > 0, CompetitionError::NoWinner);
+
+    // Nothing to challenge – the winner was derived from committed positions.
+    comp.phase = CompetitionPhase::Finalizing;
+    comp.challenge_deadline = now;
+
+    msg!(
+        "Winner {} derived on-chain from {} submissions (score {})",
+        comp.winner,
+        comp.submission_count,
+        comp.winner_score
+    );
+    Ok(())
+}
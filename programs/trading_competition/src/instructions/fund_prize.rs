@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount, Transfer};
+use crate::competition::*;
+use crate::events::PrizeFunded;
+
+#[derive(Accounts)]
+pub struct FundPrize<'info> {
+    #[account(
+        mut,
+        has_one = usdc_mint,
+        constraint = competition.phase != CompetitionPhase::Settled @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = funder,
+        token::mint = usdc_mint,
+        token::authority = competition,
+        seeds = [b"prize_vault", competition.key().as_ref()],
+        bump
+    )]
+    pub prize_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = funder
+    )]
+    pub funder_usdc_ata: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Open to anyone (sponsors, the treasurer, the community) – funds only go in.
+pub fn handler(ctx: Context<FundPrize>, amount: u64) -> Result<()> {
+    require!(amount > 0, CompetitionError::InsufficientFunds);
+
+    anchor_spl::token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.funder_usdc_ata.to_account_info(),
+                to: ctx.accounts.prize_vault.to_account_info(),
+                authority: ctx.accounts.funder.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let comp = &mut ctx.accounts.competition;
+    comp.prize_pool = comp
+        .prize_pool
+        .checked_add(amount)
+        .ok_or(CompetitionError::CalculationError)?;
+
+    emit!(PrizeFunded {
+        competition: comp.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+        prize_pool: comp.prize_pool,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
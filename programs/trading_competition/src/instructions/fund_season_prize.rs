@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount, Transfer};
+use crate::competition::*;
+
+#[derive(Accounts)]
+pub struct FundSeasonPrize<'info> {
+    #[account(
+        mut,
+        has_one = usdc_mint,
+        constraint = !season.prize_claimed @ CompetitionError::AlreadyClaimed
+    )]
+    pub season: Account<'info, Season>,
+
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = funder,
+        token::mint = usdc_mint,
+        token::authority = season,
+        seeds = [b"season_vault", season.key().as_ref()],
+        bump
+    )]
+    pub season_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = funder
+    )]
+    pub funder_usdc_ata: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FundSeasonPrize>, amount: u64) -> Result<()> {
+    require!(amount > 0, CompetitionError::InsufficientFunds);
+
+    anchor_spl::token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.funder_usdc_ata.to_account_info(),
+                to: ctx.accounts.season_vault.to_account_info(),
+                authority: ctx.accounts.funder.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let season = &mut ctx.accounts.season;
+    season.prize_pool = season
+        .prize_pool
+        .checked_add(amount)
+        .ok_or(CompetitionError::CalculationError)?;
+
+    Ok(())
+}
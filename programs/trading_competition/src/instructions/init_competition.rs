@@ -38,10 +38,16 @@ pub fn handler(
     duration: i64,
     usdc_mint: Pubkey,
     er_instance: Pubkey,
+    config: CompetitionConfig,
 ) -> Result<()> {
     let competition = &mut ctx.accounts.competition;
     let clock = Clock::get()?;
 
+    require!(
+        config.max_team_size as usize <= MAX_TEAM_SIZE,
+        CompetitionError::InvalidConfig
+    );
+
     // Immutable borrow first
     let mock_price_key = ctx.accounts.mock_price.key();
     let mock_price = &mut ctx.accounts.mock_price;
@@ -50,13 +56,37 @@ pub fn handler(
     competition.usdc_mint = usdc_mint;
     competition.er_instance = er_instance;
     competition.mock_price_pda = mock_price_key;
-    competition.phase = CompetitionPhase::Active;
+    // Registration only; start_competition opens trading and re-anchors the window
+    competition.phase = CompetitionPhase::Upcoming;
     competition.start_time = clock.unix_timestamp;
     competition.end_time = competition.start_time + duration;
     competition.challenge_deadline = competition.end_time + 3600;
     competition.winner = Pubkey::default();
     competition.winner_profit = 0;
+    competition.winner_score = 0;
     competition.state_root = [0u8; 32];
+    competition.config = config;
+    competition.participant_count = 0;
+    competition.submission_count = 0;
+    competition.open_challenges = 0;
+    competition.leaderboard = [LeaderboardEntry::default(); LEADERBOARD_SIZE];
+    competition.leaderboard_len = 0;
+    competition.winner_count = 0;
+    competition.prize_pool = 0;
+    competition.prize_claimed = 0;
+    competition.team_leaderboard = [TeamEntry::default(); TEAM_LEADERBOARD_SIZE];
+    competition.team_leaderboard_len = 0;
+    competition.untallied_members = 0;
+    competition.winning_team = Pubkey::default();
+    competition.season = Pubkey::default();
+    competition.season_awarded = 0;
+    competition.season_pending = false;
+    competition.registration_deadline = 0;
+    competition.allowlist_root = [0u8; 32];
+    competition.field_rating_sum = 0;
+    competition.review_deadline = 0;
+    competition.uri_template = String::new();
+    competition.roles = Roles::all(ctx.accounts.authority.key());
     competition.bump = ctx.bumps.competition;
 
     // Mutable borrow: update price
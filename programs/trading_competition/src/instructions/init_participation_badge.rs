@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::instructions::mint_soulbound_trophy::{create_soulbound_mint, MintMetadata, SoulboundMintAccounts};
+use anchor_spl::token_interface::Token2022;
+
+const BADGE_NAME: &str = "Cypherpunk Participant";
+const BADGE_SYMBOL: &str = "CYPART";
+
+/// Shared, non-transferable Token-2022 mint every finisher of the competition
+/// claims from.
+///
+/// The mint is shared, so its metadata can't carry per-holder data and its URI
+/// stays empty. A holder's rank and profit live in their `ParticipationRecord`,
+/// found with `find_program_address(["participation", competition, holder], program)`
+/// – the `holder_record_seeds` and `program` attributes spell this out.
+#[derive(Accounts)]
+pub struct InitParticipationBadge<'info> {
+    #[account(constraint = treasurer.key() == competition.roles.treasurer @ CompetitionError::Unauthorized)]
+    pub competition: Account<'info, Competition>,
+
+    #[account(mut)]
+    pub treasurer: Signer<'info>,
+
+    /// CHECK: Created in the handler – NonTransferable + MetadataPointer extensions
+    #[account(
+        mut,
+        seeds = [b"participation_mint", competition.key().as_ref()],
+        bump
+    )]
+    pub participation_mint: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitParticipationBadge>) -> Result<()> {
+    let comp = &ctx.accounts.competition;
+    let comp_key = comp.key();
+    let mint = ctx.accounts.participation_mint.to_account_info();
+
+    create_soulbound_mint(
+        &SoulboundMintAccounts {
+            payer: ctx.accounts.treasurer.to_account_info(),
+            mint: mint.clone(),
+            authority: ctx.accounts.competition.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        &[&[b"participation_mint", comp_key.as_ref(), &[ctx.bumps.participation_mint]]],
+        &[&[b"competition", comp.authority.as_ref(), &[comp.bump]]],
+        MintMetadata {
+            name: BADGE_NAME.to_string(),
+            symbol: BADGE_SYMBOL.to_string(),
+            uri: String::new(),
+            attributes: vec![
+                ("competition".to_string(), comp_key.to_string()),
+                (
+                    "holder_record_seeds".to_string(),
+                    format!("[\"participation\", {comp_key}, <holder wallet>]"),
+                ),
+                ("program".to_string(), crate::ID.to_string()),
+            ],
+        },
+    )?;
+
+    msg!("Participation badge {} ready for {}", mint.key(), comp_key);
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::competition::*;
+
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct InitSeason<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Season::INIT_SPACE,
+        seeds = [b"season", authority.key().as_ref(), &season_id.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub usdc_mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitSeason>,
+    season_id: u64,
+    points_table: [u32; LEADERBOARD_SIZE],
+) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+
+    season.authority = ctx.accounts.authority.key();
+    season.season_id = season_id;
+    season.usdc_mint = ctx.accounts.usdc_mint.key();
+    season.points_table = points_table;
+    season.competition_count = 0;
+    season.pending_competitions = 0;
+    season.champion = Pubkey::default();
+    season.champion_points = 0;
+    season.settled = false;
+    season.prize_pool = 0;
+    season.prize_claimed = false;
+    season.bump = ctx.bumps.season;
+
+    Ok(())
+}
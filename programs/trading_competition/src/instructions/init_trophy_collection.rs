@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use mpl_token_metadata::instructions::{
+    CreateMasterEditionV3, CreateMasterEditionV3InstructionArgs, CreateMetadataAccountV3,
+    CreateMetadataAccountV3InstructionArgs,
+};
+use mpl_token_metadata::types::{CollectionDetails, Creator, DataV2};
+
+/// One sized collection per competition authority; every winner NFT is verified into it.
+/// Anyone may pay to create it so winners aren't stuck waiting on the authority.
+#[derive(Accounts)]
+pub struct InitTrophyCollection<'info> {
+    /// Any of the authority's competitions – its `authority` seeds the collection
+    pub competition: Account<'info, Competition>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: PDA – update authority of the collection, signs verifications
+    #[account(seeds = [b"collection_authority", competition.authority.as_ref()], bump)]
+    pub collection_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = collection_authority,
+        mint::freeze_authority = collection_authority,
+        seeds = [b"collection_mint", competition.authority.as_ref()],
+        bump
+    )]
+    pub collection_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = collection_mint,
+        token::authority = collection_authority,
+        seeds = [b"collection_token", competition.authority.as_ref()],
+        bump
+    )]
+    pub collection_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.to_bytes().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program
+    )]
+    /// CHECK: Metaplex metadata PDA
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.to_bytes().as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program
+    )]
+    /// CHECK: Metaplex master edition PDA
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<InitTrophyCollection>, metadata_uri: String) -> Result<()> {
+    let authority_key = ctx.accounts.competition.authority;
+    let payer_key = ctx.accounts.payer.key();
+    // Only the authority picks the collection's artwork; anyone else gets an empty URI
+    require!(
+        metadata_uri.is_empty() || payer_key == authority_key,
+        CompetitionError::Unauthorized
+    );
+    require!(metadata_uri.len() <= MAX_URI_LEN, CompetitionError::UriTooLong);
+    let collection_authority = ctx.accounts.collection_authority.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"collection_authority",
+        authority_key.as_ref(),
+        &[ctx.bumps.collection_authority]
+    ]];
+
+    // Mint the single collection token
+    anchor_spl::token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::MintTo {
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                to: ctx.accounts.collection_token_account.to_account_info(),
+                authority: ctx.accounts.collection_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    // Sized collection metadata
+    let data = DataV2 {
+        name: "Cypherpunk Trophies".to_string(),
+        symbol: "CYPHR".to_string(),
+        uri: metadata_uri,
+        seller_fee_basis_points: 0,
+        creators: Some(vec![Creator {
+            address: collection_authority,
+            verified: true,
+            share: 100,
+        }]),
+        collection: None,
+        uses: None,
+    };
+
+    let ix = CreateMetadataAccountV3 {
+        metadata: ctx.accounts.collection_metadata.key(),
+        mint: ctx.accounts.collection_mint.key(),
+        mint_authority: collection_authority,
+        payer: payer_key,
+        update_authority: (collection_authority, true),
+        system_program: ctx.accounts.system_program.key(),
+        rent: Some(ctx.accounts.rent.key()),
+    }
+    .instruction(CreateMetadataAccountV3InstructionArgs {
+        data,
+        is_mutable: true,
+        collection_details: Some(CollectionDetails::V1 { size: 0 }),
+    });
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.collection_metadata.to_account_info(),
+            ctx.accounts.collection_mint.to_account_info(),
+            ctx.accounts.collection_authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+
+    // Master edition – required for a collection parent; locks supply at 1
+    let ix = CreateMasterEditionV3 {
+        edition: ctx.accounts.collection_master_edition.key(),
+        mint: ctx.accounts.collection_mint.key(),
+        update_authority: collection_authority,
+        mint_authority: collection_authority,
+        payer: payer_key,
+        metadata: ctx.accounts.collection_metadata.key(),
+        token_program: ctx.accounts.token_program.key(),
+        system_program: ctx.accounts.system_program.key(),
+        rent: Some(ctx.accounts.rent.key()),
+    }
+    .instruction(CreateMasterEditionV3InstructionArgs {
+        max_supply: Some(0),
+    });
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.collection_master_edition.to_account_info(),
+            ctx.accounts.collection_mint.to_account_info(),
+            ctx.accounts.collection_authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.collection_metadata.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+
+    msg!("Trophy collection {} created for {}", ctx.accounts.collection_mint.key(), authority_key);
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::TeamJoined;
+
+#[derive(Accounts)]
+pub struct JoinTeam<'info> {
+    #[account(
+        mut,
+        constraint = matches!(competition.phase, CompetitionPhase::Upcoming | CompetitionPhase::Active)
+            @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    #[account(
+        mut,
+        has_one = competition,
+        has_one = captain,
+        seeds = [b"team", competition.key().as_ref(), captain.key().as_ref()],
+        bump = team.bump
+    )]
+    pub team: Account<'info, Team>,
+
+    #[account(
+        init,
+        payer = member,
+        space = 8 + TeamMember::INIT_SPACE,
+        seeds = [b"team_member", competition.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub membership: Account<'info, TeamMember>,
+
+    /// CHECK: member's Position PDA – may sit in the ER, only existence is checked
+    #[account(
+        seeds = [b"position", competition.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub member_position: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    /// Captain co-signs every join
+    pub captain: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<JoinTeam>) -> Result<()> {
+    let comp = &mut ctx.accounts.competition;
+    let team = &mut ctx.accounts.team;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Open throughout Upcoming, whatever the provisional end_time
+    require!(
+        comp.phase == CompetitionPhase::Upcoming || now < comp.end_time,
+        CompetitionError::NotActive
+    );
+    require!(
+        team.member_count < comp.config.max_team_size,
+        CompetitionError::TeamFull
+    );
+    require!(
+        !ctx.accounts.member_position.data_is_empty(),
+        CompetitionError::NotRegistered
+    );
+
+    let member = ctx.accounts.member.key();
+    let slot = team.member_count as usize;
+    team.members[slot] = member;
+    team.member_count += 1;
+    comp.untallied_members = comp
+        .untallied_members
+        .checked_add(1)
+        .ok_or(CompetitionError::CalculationError)?;
+
+    let membership = &mut ctx.accounts.membership;
+    membership.team = team.key();
+    membership.user = member;
+    membership.bump = ctx.bumps.membership;
+
+    emit!(TeamJoined {
+        team: team.key(),
+        competition: comp.key(),
+        member,
+        member_count: team.member_count,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,401 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+use crate::competition::*;
+use crate::events::TrophyMinted;
+use crate::trophy_svg;
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken};
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_interface::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::{Field, TokenMetadata};
+use anchor_spl::token_interface::{
+    self, InitializeMint2, MetadataPointerInitialize, MintTo, NonTransferableMintInitialize,
+    SetAuthority, Token2022, TokenMetadataInitialize, TokenMetadataUpdateField,
+};
+
+#[derive(Accounts)]
+pub struct MintSoulboundTrophy<'info> {
+    #[account(
+        constraint = treasurer.key() == competition.roles.treasurer @ CompetitionError::Unauthorized,
+        constraint = competition.phase == CompetitionPhase::Settled @ CompetitionError::NotActive,
+        constraint = competition.config.trophy == TrophyKind::Soulbound @ CompetitionError::WrongTrophyKind
+    )]
+    pub competition: Account<'info, Competition>,
+
+    #[account(mut)]
+    pub treasurer: Signer<'info>,
+
+    #[account(
+        has_one = competition,
+        constraint = competition.rank_of(&winner_position.user)
+            .and_then(TrophyTier::from_rank)
+            .is_some() @ CompetitionError::NotWinner,
+        constraint = winner_position.is_eligible(&competition.config.eligibility) @ CompetitionError::NotEligible,
+        seeds = [b"position", competition.key().as_ref(), winner_position.user.as_ref()],
+        bump = winner_position.bump
+    )]
+    pub winner_position: Account<'info, Position>,
+
+    /// CHECK: Verified by constraint
+    #[account(address = winner_position.user @ CompetitionError::Unauthorized)]
+    pub winner_wallet: UncheckedAccount<'info>,
+
+    /// CHECK: Created in the handler – NonTransferable + MetadataPointer extensions
+    #[account(
+        mut,
+        seeds = [b"trophy_mint", competition.key().as_ref(), winner_position.user.as_ref()],
+        bump
+    )]
+    pub trophy_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Winner's Token-2022 ATA, created in the handler
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &winner_wallet.key(),
+            &trophy_mint.key(),
+            &token_program.key()
+        )
+    )]
+    pub trophy_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MintSoulboundTrophy>) -> Result<()> {
+    let accounts = SoulboundTrophyAccounts {
+        payer: ctx.accounts.treasurer.to_account_info(),
+        winner_wallet: ctx.accounts.winner_wallet.to_account_info(),
+        trophy_mint: ctx.accounts.trophy_mint.to_account_info(),
+        trophy_account: ctx.accounts.trophy_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+    mint_soulbound_trophy(
+        accounts,
+        &ctx.accounts.competition,
+        &ctx.accounts.winner_position,
+        ctx.bumps.trophy_mint,
+    )
+}
+
+/// Accounts the trophy mint needs – shared by `mint_soulbound_trophy` and
+/// `claim_soulbound_trophy`.
+pub(crate) struct SoulboundTrophyAccounts<'info> {
+    pub payer: AccountInfo<'info>,
+    pub winner_wallet: AccountInfo<'info>,
+    pub trophy_mint: AccountInfo<'info>,
+    pub trophy_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Create the trophy mint with its metadata, mint the single token to the
+/// winner and drop the mint authority.
+pub(crate) fn mint_soulbound_trophy<'info>(
+    accounts: SoulboundTrophyAccounts<'info>,
+    competition: &Account<'info, Competition>,
+    winner_position: &Account<'info, Position>,
+    trophy_mint_bump: u8,
+) -> Result<()> {
+    let comp = competition;
+    let pos = winner_position;
+    let token_program = accounts.token_program;
+    let mint = accounts.trophy_mint;
+
+    let comp_key = comp.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"competition",
+        comp.authority.as_ref(),
+        &[comp.bump]
+    ]];
+    let mint_seeds: &[&[&[u8]]] = &[&[
+        b"trophy_mint",
+        comp_key.as_ref(),
+        pos.user.as_ref(),
+        &[trophy_mint_bump]
+    ]];
+
+    let TrophyFields { rank, metadata } = trophy_fields(comp, &comp_key, pos)?;
+
+    create_soulbound_mint(
+        &SoulboundMintAccounts {
+            payer: accounts.payer.clone(),
+            mint: mint.clone(),
+            authority: comp.to_account_info(),
+            token_program: token_program.clone(),
+            system_program: accounts.system_program.clone(),
+        },
+        mint_seeds,
+        signer_seeds,
+        metadata,
+    )?;
+
+    // ---- Mint the single trophy and fix the supply ----
+    associated_token::create(CpiContext::new(
+        accounts.associated_token_program,
+        associated_token::Create {
+            payer: accounts.payer,
+            associated_token: accounts.trophy_account.clone(),
+            authority: accounts.winner_wallet,
+            mint: mint.clone(),
+            system_program: accounts.system_program,
+            token_program: token_program.clone(),
+        },
+    ))?;
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            MintTo {
+                mint: mint.clone(),
+                to: accounts.trophy_account,
+                authority: comp.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+    token_interface::set_authority(
+        CpiContext::new_with_signer(
+            token_program,
+            SetAuthority {
+                current_authority: comp.to_account_info(),
+                account_or_mint: mint.clone(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    emit!(TrophyMinted {
+        holder: pos.user,
+        competition: comp_key,
+        mint: mint.key(),
+        rank: rank as u8 + 1,
+        profit: pos.profit(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// A soulbound trophy's metadata and the rank it was derived from.
+pub struct TrophyFields {
+    pub rank: usize,
+    pub metadata: MintMetadata,
+}
+
+/// Derive the trophy's metadata from the holder's current rank – shared by
+/// minting and `update_soulbound_trophy_metadata`.
+pub fn trophy_fields(comp: &Competition, comp_key: &Pubkey, pos: &Position) -> Result<TrophyFields> {
+    let rank = comp.rank_of(&pos.user).ok_or(CompetitionError::NotWinner)?;
+    let tier = TrophyTier::from_rank(rank).ok_or(CompetitionError::NotWinner)?;
+    let name = tier.name().to_string();
+    let symbol = tier.symbol().to_string();
+
+    // On-chain attributes – readable without the off-chain URI
+    let mut attributes = vec![
+        ("competition".to_string(), comp_key.to_string()),
+        ("rank".to_string(), (rank + 1).to_string()),
+        ("profit".to_string(), pos.profit().to_string()),
+        ("roi_bps".to_string(), pos.roi_bps().to_string()),
+        ("start_time".to_string(), comp.start_time.to_string()),
+        ("end_time".to_string(), comp.end_time.to_string()),
+    ];
+
+    // Artwork is rendered here and stored once: inside the embedded JSON, or
+    // as the `image` attribute when the URI template points off-chain
+    let svg = trophy_svg::render(tier, comp_key, rank, pos.profit(), comp.end_time);
+    let metadata_uri = if comp.uri_template.is_empty() {
+        trophy_svg::data_uri(
+            "application/json",
+            trophy_svg::metadata_json(&name, &symbol, &svg)?.as_bytes(),
+        )?
+    } else {
+        attributes.push((
+            "image".to_string(),
+            trophy_svg::data_uri("image/svg+xml", svg.as_bytes())?,
+        ));
+        render_uri(&comp.uri_template, comp_key, &pos.user)
+    };
+
+    Ok(TrophyFields {
+        rank,
+        metadata: MintMetadata { name, symbol, uri: metadata_uri, attributes },
+    })
+}
+
+/// Name, symbol, URI and on-chain attributes written into a Token-2022 mint.
+pub struct MintMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub attributes: Vec<(String, String)>,
+}
+
+/// Accounts `create_soulbound_mint` needs.
+pub(crate) struct SoulboundMintAccounts<'info> {
+    pub payer: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,  // PDA – mint and metadata update authority
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Create a NonTransferable Token-2022 mint at a PDA with its metadata stored
+/// in the mint itself – shared by trophies, achievement and participation badges.
+pub(crate) fn create_soulbound_mint<'info>(
+    accounts: &SoulboundMintAccounts<'info>,
+    mint_seeds: &[&[&[u8]]],
+    authority_seeds: &[&[&[u8]]],
+    metadata: MintMetadata,
+) -> Result<()> {
+    let token_program = &accounts.token_program;
+    let mint = &accounts.mint;
+    let authority_key = accounts.authority.key();
+
+    // ---- Allocate the mint; rent also covers the metadata written below ----
+    let mint_len = ExtensionType::try_calculate_account_len::<MintState>(&[
+        ExtensionType::NonTransferable,
+        ExtensionType::MetadataPointer,
+    ])?;
+    let metadata_len = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(authority_key))?,
+        mint: mint.key(),
+        name: metadata.name.clone(),
+        symbol: metadata.symbol.clone(),
+        uri: metadata.uri.clone(),
+        additional_metadata: metadata.attributes.clone(),
+    }
+    .tlv_size_of()?;
+    create_pda_account(
+        &accounts.payer,
+        mint,
+        &accounts.system_program,
+        mint_seeds,
+        Rent::get()?.minimum_balance(mint_len + metadata_len),
+        mint_len,
+        &token_program.key(),
+    )?;
+
+    // ---- Extensions must be initialised before the mint itself ----
+    token_interface::non_transferable_mint_initialize(CpiContext::new(
+        token_program.clone(),
+        NonTransferableMintInitialize {
+            token_program_id: token_program.clone(),
+            mint: mint.clone(),
+        },
+    ))?;
+    token_interface::metadata_pointer_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            MetadataPointerInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint.clone(),
+            },
+        ),
+        Some(authority_key),
+        Some(mint.key()),
+    )?;
+    token_interface::initialize_mint2(
+        CpiContext::new(token_program.clone(), InitializeMint2 { mint: mint.clone() }),
+        0,
+        &authority_key,
+        None,
+    )?;
+
+    // ---- Metadata lives in the mint – no Metaplex account ----
+    token_interface::token_metadata_initialize(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TokenMetadataInitialize {
+                program_id: token_program.clone(),
+                metadata: mint.clone(),
+                update_authority: accounts.authority.clone(),
+                mint_authority: accounts.authority.clone(),
+                mint: mint.clone(),
+            },
+            authority_seeds,
+        ),
+        metadata.name,
+        metadata.symbol,
+        metadata.uri,
+    )?;
+    for (key, value) in metadata.attributes {
+        token_interface::token_metadata_update_field(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenMetadataUpdateField {
+                    program_id: token_program.clone(),
+                    metadata: mint.clone(),
+                    update_authority: accounts.authority.clone(),
+                },
+                authority_seeds,
+            ),
+            Field::Key(key),
+            value,
+        )?;
+    }
+    Ok(())
+}
+
+/// Create a PDA owned by `owner`. A pre-funded PDA would make create_account
+/// fail, so it is topped up, allocated and assigned instead – the same path
+/// Anchor's `init` takes.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    lamports: u64,
+    space: usize,
+    owner: &Pubkey,
+) -> Result<()> {
+    let current_lamports = account.lamports();
+    if current_lamports == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount { from: payer.clone(), to: account.clone() },
+                signer_seeds,
+            ),
+            lamports,
+            space as u64,
+            owner,
+        );
+    }
+    let top_up = lamports.saturating_sub(current_lamports);
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer { from: payer.clone(), to: account.clone() },
+            ),
+            top_up,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate { account_to_allocate: account.clone() },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign { account_to_assign: account.clone() },
+            signer_seeds,
+        ),
+        owner,
+    )
+}
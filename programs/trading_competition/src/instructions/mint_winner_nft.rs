@@ -2,28 +2,31 @@ use anchor_lang::prelude::*;
 use crate::competition::*;
 use crate::events::WinnerNftMinted;
 use anchor_spl::token::{Mint, Token, TokenAccount};
-use mpl_token_metadata::instructions::{CreateMetadataAccountV3, CreateMetadataAccountV3InstructionArgs};
-use mpl_token_metadata::types::{Creator, DataV2};
+use mpl_token_metadata::instructions::{
+    CreateMetadataAccountV3, CreateMetadataAccountV3InstructionArgs, VerifySizedCollectionItem,
+};
+use mpl_token_metadata::types::{Collection, Creator, DataV2};
 
 #[derive(Accounts)]
-#[instruction(metadata_uri: String)]
 pub struct MintWinnerNft<'info> {
     #[account(
         mut,
-        has_one = authority,
-        has_one = winner,
-        constraint = competition.phase == CompetitionPhase::Settled @ CompetitionError::NotActive
+        constraint = treasurer.key() == competition.roles.treasurer @ CompetitionError::Unauthorized,
+        constraint = competition.phase == CompetitionPhase::Settled @ CompetitionError::NotActive,
+        constraint = competition.config.trophy == TrophyKind::Collection @ CompetitionError::WrongTrophyKind
     )]
     pub competition: Account<'info, Competition>,
 
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub treasurer: Signer<'info>,
 
     #[account(
         mut,
         has_one = competition,
-        has_one = user,
-        constraint = winner_position.user == competition.winner @ CompetitionError::Unauthorized,
+        constraint = competition.rank_of(&winner_position.user)
+            .and_then(TrophyTier::from_rank)
+            .is_some() @ CompetitionError::NotWinner,
+        constraint = winner_position.is_eligible(&competition.config.eligibility) @ CompetitionError::NotEligible,
         seeds = [b"position", competition.key().as_ref(), winner_position.user.as_ref()],
         bump = winner_position.bump
     )]
@@ -35,7 +38,7 @@ pub struct MintWinnerNft<'info> {
 
     #[account(
         init,
-        payer = authority,
+        payer = treasurer,
         mint::decimals = 0,
         mint::authority = competition,
         seeds = [b"nft_mint", competition.key().as_ref(), winner_position.user.as_ref()],
@@ -45,7 +48,7 @@ pub struct MintWinnerNft<'info> {
 
     #[account(
         init,
-        payer = authority,
+        payer = treasurer,
         token::mint = nft_mint,
         token::authority = winner_wallet
     )]
@@ -64,6 +67,39 @@ pub struct MintWinnerNft<'info> {
     /// CHECK: Metaplex metadata PDA
     pub metadata_account: UncheckedAccount<'info>,
 
+    /// CHECK: PDA – signs the collection verification
+    #[account(seeds = [b"collection_authority", competition.authority.as_ref()], bump)]
+    pub collection_authority: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"collection_mint", competition.authority.as_ref()], bump)]
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.to_bytes().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program
+    )]
+    /// CHECK: Metaplex metadata PDA of the collection
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.to_bytes().as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program
+    )]
+    /// CHECK: Metaplex master edition PDA of the collection
+    pub collection_master_edition: UncheckedAccount<'info>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     #[account(address = mpl_token_metadata::ID)]
@@ -72,9 +108,60 @@ pub struct MintWinnerNft<'info> {
     pub clock: Sysvar<'info, Clock>,
 }
 
-pub fn handler(ctx: Context<MintWinnerNft>, metadata_uri: String) -> Result<()> {
-    let comp = &ctx.accounts.competition;
-    let pos = &ctx.accounts.winner_position;
+pub fn handler(ctx: Context<MintWinnerNft>) -> Result<()> {
+    let accounts = WinnerNftAccounts {
+        payer: ctx.accounts.treasurer.to_account_info(),
+        nft_mint: ctx.accounts.nft_mint.to_account_info(),
+        nft_account: ctx.accounts.nft_account.to_account_info(),
+        metadata_account: ctx.accounts.metadata_account.to_account_info(),
+        collection_authority: ctx.accounts.collection_authority.to_account_info(),
+        collection_mint: ctx.accounts.collection_mint.to_account_info(),
+        collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+        collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    mint_winner_nft(
+        accounts,
+        &ctx.accounts.competition,
+        &ctx.accounts.winner_position,
+        ctx.bumps.collection_authority,
+    )
+}
+
+/// Accounts the mint itself needs – shared by `mint_winner_nft` and `claim_winner_nft`.
+pub(crate) struct WinnerNftAccounts<'info> {
+    pub payer: AccountInfo<'info>,
+    pub nft_mint: AccountInfo<'info>,
+    pub nft_account: AccountInfo<'info>,
+    pub metadata_account: AccountInfo<'info>,
+    pub collection_authority: AccountInfo<'info>,
+    pub collection_mint: AccountInfo<'info>,
+    pub collection_metadata: AccountInfo<'info>,
+    pub collection_master_edition: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+/// Mint the trophy, create its metadata and verify it into the collection.
+///
+/// Unlike the soulbound trophy, the on-chain SVG is not embedded here: the
+/// Metaplex URI is capped at `MAX_URI_LEN` (200 bytes), far below any data
+/// URI of the artwork. With a `uri_template` the JSON and image are hosted
+/// off-chain; without one the URI is left empty and the trophy carries only
+/// its name, symbol and collection.
+pub(crate) fn mint_winner_nft<'info>(
+    accounts: WinnerNftAccounts<'info>,
+    competition: &Account<'info, Competition>,
+    winner_position: &Account<'info, Position>,
+    collection_authority_bump: u8,
+) -> Result<()> {
+    let comp = competition;
+    let pos = winner_position;
 
     let signer_seeds: &[&[&[u8]]] = &[&[
         b"competition",
@@ -85,11 +172,11 @@ pub fn handler(ctx: Context<MintWinnerNft>, metadata_uri: String) -> Result<()>
     // Mint the NFT
     anchor_spl::token::mint_to(
         CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
+            accounts.token_program.clone(),
             anchor_spl::token::MintTo {
-                mint: ctx.accounts.nft_mint.to_account_info(),
-                to: ctx.accounts.nft_account.to_account_info(),
-                authority: ctx.accounts.competition.to_account_info(),
+                mint: accounts.nft_mint.clone(),
+                to: accounts.nft_account.clone(),
+                authority: comp.to_account_info(),
             },
             signer_seeds,
         ),
@@ -102,29 +189,32 @@ pub fn handler(ctx: Context<MintWinnerNft>, metadata_uri: String) -> Result<()>
         verified: true,
         share: 100,
     };
-    let name = format!("Cypherpunk Winner: ${}", pos.profit())
-        .chars()
-        .take(32)
-        .collect::<String>();
+    let tier = comp
+        .rank_of(&pos.user)
+        .and_then(TrophyTier::from_rank)
+        .ok_or(CompetitionError::NotWinner)?;
 
     let data = DataV2 {
-        name,
-        symbol: "CYPHR".to_string(),
-        uri: metadata_uri,
+        name: tier.name().to_string(),
+        symbol: tier.symbol().to_string(),
+        uri: render_uri(&comp.uri_template, &comp.key(), &pos.user),  // empty without a template
         seller_fee_basis_points: 0,
         creators: Some(vec![creator]),
-        collection: None,
+        collection: Some(Collection {
+            verified: false,
+            key: accounts.collection_mint.key(),
+        }),
         uses: None,
     };
 
     let ix = CreateMetadataAccountV3 {
-        metadata: ctx.accounts.metadata_account.key(),
-        mint: ctx.accounts.nft_mint.key(),
+        metadata: accounts.metadata_account.key(),
+        mint: accounts.nft_mint.key(),
         mint_authority: comp.key(),
-        payer: ctx.accounts.authority.key(),
+        payer: accounts.payer.key(),
         update_authority: (comp.key(), true),
-        system_program: ctx.accounts.system_program.key(),
-        rent: Some(ctx.accounts.rent.key()),
+        system_program: accounts.system_program.key(),
+        rent: Some(accounts.rent.key()),
     }
     .instruction(CreateMetadataAccountV3InstructionArgs {
         data,
@@ -135,25 +225,55 @@ pub fn handler(ctx: Context<MintWinnerNft>, metadata_uri: String) -> Result<()>
     anchor_lang::solana_program::program::invoke_signed(
         &ix,
         &[
-            ctx.accounts.metadata_account.to_account_info(),
-            ctx.accounts.nft_mint.to_account_info(),
-            ctx.accounts.competition.to_account_info(),
-            ctx.accounts.authority.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-            ctx.accounts.token_metadata_program.to_account_info(),
-            ctx.accounts.rent.to_account_info(),
+            accounts.metadata_account.clone(),
+            accounts.nft_mint.clone(),
+            comp.to_account_info(),
+            accounts.payer.clone(),
+            accounts.system_program.clone(),
+            accounts.token_metadata_program.clone(),
+            accounts.rent.clone(),
         ],
         signer_seeds,
     )?;
 
+    // Verify the NFT into the authority's trophy collection
+    let ix = VerifySizedCollectionItem {
+        metadata: accounts.metadata_account.key(),
+        collection_authority: accounts.collection_authority.key(),
+        payer: accounts.payer.key(),
+        collection_mint: accounts.collection_mint.key(),
+        collection: accounts.collection_metadata.key(),
+        collection_master_edition_account: accounts.collection_master_edition.key(),
+        collection_authority_record: None,
+    }
+    .instruction();
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &ix,
+        &[
+            accounts.metadata_account.clone(),
+            accounts.collection_authority.clone(),
+            accounts.payer.clone(),
+            accounts.collection_mint.clone(),
+            accounts.collection_metadata.clone(),
+            accounts.collection_master_edition.clone(),
+            accounts.token_metadata_program.clone(),
+        ],
+        &[&[
+            b"collection_authority",
+            comp.authority.as_ref(),
+            &[collection_authority_bump],
+        ]],
+    )?;
+
     // Emit event
     emit!(WinnerNftMinted {
         winner: pos.user,
         competition: comp.key(),
-        nft_mint: ctx.accounts.nft_mint.key(),
+        nft_mint: accounts.nft_mint.key(),
         profit: pos.profit(),
         timestamp: Clock::get()?.unix_timestamp,
     });
 
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,17 +1,138 @@
+// Every module exports a `handler`; the globs below only need to surface the
+// Accounts structs and the client modules `#[program]` looks up at the crate root.
+#![allow(ambiguous_glob_reexports)]
+
 pub mod init_competition;
+pub mod start_competition;
 pub mod delegate_accounts;
+pub mod delegate_position;
 pub mod process_trade;
 pub mod final_commit;
 pub mod user_undelegate;
 pub mod settle_competition;
 pub mod mint_winner_nft;
 pub mod update_mock_price;
+pub mod verify_position;
+pub mod challenge_winner;
+pub mod resolve_challenge;
+pub mod submit_position;
+pub mod finalize_winner;
+pub mod fund_prize;
+pub mod claim_prize;
+pub mod set_registration;
+pub mod create_team;
+pub mod join_team;
+pub mod tally_team_member;
+pub mod init_season;
+pub mod add_competition_to_season;
+pub mod remove_competition_from_season;
+pub mod award_season_points;
+pub mod fund_season_prize;
+pub mod settle_season;
+pub mod claim_season_prize;
+pub mod update_rating;
+pub mod set_trader_handle;
+pub mod mint_soulbound_trophy;
+pub mod init_trophy_collection;
+pub mod claim_winner_nft;
+pub mod claim_soulbound_trophy;
+pub mod set_uri_template;
+pub mod update_winner_nft_metadata;
+pub mod update_soulbound_trophy_metadata;
+pub mod revoke_trophy;
+pub mod revoke_soulbound_trophy;
+pub mod claim_achievement;
+pub mod init_participation_badge;
+pub mod claim_participation_badge;
+pub mod set_role;
+pub mod overturn_result;
 
 pub use init_competition::handler as init_competition_handler;
 pub use delegate_accounts::handler as delegate_accounts_handler;
+pub use delegate_position::handler as delegate_position_handler;
 pub use process_trade::handler as process_trade_handler;
 pub use final_commit::handler as final_commit_handler;
 pub use user_undelegate::handler as user_undelegate_handler;
 pub use settle_competition::handler as settle_competition_handler;
 pub use mint_winner_nft::handler as mint_winner_nft_handler;
-pub use update_mock_price::handler as update_mock_price_handler;
\ No newline at end of file
+pub use update_mock_price::handler as update_mock_price_handler;
+pub use verify_position::handler as verify_position_handler;
+pub use challenge_winner::handler as challenge_winner_handler;
+pub use resolve_challenge::handler as resolve_challenge_handler;
+pub use submit_position::handler as submit_position_handler;
+pub use finalize_winner::handler as finalize_winner_handler;
+pub use fund_prize::handler as fund_prize_handler;
+pub use claim_prize::handler as claim_prize_handler;
+pub use set_registration::handler as set_registration_handler;
+pub use create_team::handler as create_team_handler;
+pub use join_team::handler as join_team_handler;
+pub use tally_team_member::handler as tally_team_member_handler;
+pub use init_season::handler as init_season_handler;
+pub use add_competition_to_season::handler as add_competition_to_season_handler;
+pub use remove_competition_from_season::handler as remove_competition_from_season_handler;
+pub use award_season_points::handler as award_season_points_handler;
+pub use fund_season_prize::handler as fund_season_prize_handler;
+pub use settle_season::handler as settle_season_handler;
+pub use claim_season_prize::handler as claim_season_prize_handler;
+pub use update_rating::handler as update_rating_handler;
+pub use set_trader_handle::handler as set_trader_handle_handler;
+pub use mint_soulbound_trophy::handler as mint_soulbound_trophy_handler;
+pub use init_trophy_collection::handler as init_trophy_collection_handler;
+pub use claim_winner_nft::handler as claim_winner_nft_handler;
+pub use claim_soulbound_trophy::handler as claim_soulbound_trophy_handler;
+pub use set_uri_template::handler as set_uri_template_handler;
+pub use update_winner_nft_metadata::handler as update_winner_nft_metadata_handler;
+pub use update_soulbound_trophy_metadata::handler as update_soulbound_trophy_metadata_handler;
+pub use revoke_trophy::handler as revoke_trophy_handler;
+pub use revoke_soulbound_trophy::handler as revoke_soulbound_trophy_handler;
+pub use claim_achievement::handler as claim_achievement_handler;
+pub use init_participation_badge::handler as init_participation_badge_handler;
+pub use claim_participation_badge::handler as claim_participation_badge_handler;
+pub use set_role::handler as set_role_handler;
+pub use start_competition::handler as start_competition_handler;
+pub use overturn_result::handler as overturn_result_handler;
+
+pub use init_competition::*;
+pub use start_competition::*;
+pub use delegate_accounts::*;
+pub use delegate_position::*;
+pub use process_trade::*;
+pub use final_commit::*;
+pub use user_undelegate::*;
+pub use settle_competition::*;
+pub use mint_winner_nft::*;
+pub use update_mock_price::*;
+pub use verify_position::*;
+pub use challenge_winner::*;
+pub use resolve_challenge::*;
+pub use submit_position::*;
+pub use finalize_winner::*;
+pub use fund_prize::*;
+pub use claim_prize::*;
+pub use set_registration::*;
+pub use create_team::*;
+pub use join_team::*;
+pub use tally_team_member::*;
+pub use init_season::*;
+pub use add_competition_to_season::*;
+pub use remove_competition_from_season::*;
+pub use award_season_points::*;
+pub use fund_season_prize::*;
+pub use settle_season::*;
+pub use claim_season_prize::*;
+pub use update_rating::*;
+pub use set_trader_handle::*;
+pub use mint_soulbound_trophy::*;
+pub use init_trophy_collection::*;
+pub use claim_winner_nft::*;
+pub use claim_soulbound_trophy::*;
+pub use set_uri_template::*;
+pub use update_winner_nft_metadata::*;
+pub use update_soulbound_trophy_metadata::*;
+pub use revoke_trophy::*;
+pub use revoke_soulbound_trophy::*;
+pub use claim_achievement::*;
+pub use init_participation_badge::*;
+pub use claim_participation_badge::*;
+pub use set_role::*;
+pub use overturn_result::*;
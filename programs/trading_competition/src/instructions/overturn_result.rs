@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::ResultOverturned;
+
+#[derive(Accounts)]
+pub struct OverturnResult<'info> {
+    #[account(
+        mut,
+        constraint = moderator.key() == competition.roles.moderator @ CompetitionError::Unauthorized,
+        constraint = competition.phase == CompetitionPhase::Settled @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    pub moderator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = competition,
+        constraint = !position.overturned @ CompetitionError::AlreadyOverturned,
+        seeds = [b"position", competition.key().as_ref(), position.user.as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+}
+
+/// Post-settlement correction (e.g. a rules breach found after the challenge
+/// window): the trader leaves the board, everyone below moves up, and
+/// the revoke and metadata-update instructions bring both trophy kinds in line.
+/// Only open during the review window – prizes, season points and ratings
+/// are all computed from ranks, and none of them apply until it closes.
+pub fn handler(ctx: Context<OverturnResult>) -> Result<()> {
+    let comp = &mut ctx.accounts.competition;
+    let pos = &mut ctx.accounts.position;
+    require!(
+        Clock::get()?.unix_timestamp < comp.review_deadline,
+        CompetitionError::ReviewClosed
+    );
+
+    let previous_rank = comp.rank_of(&pos.user);
+    comp.remove(&pos.user);
+    pos.overturned = true;
+
+    emit!(ResultOverturned {
+        competition: comp.key(),
+        trader: pos.user,
+        previous_rank: previous_rank.map_or(0, |r| r as u16 + 1),
+        new_winner: comp.winner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Result of {} in {} overturned", pos.user, comp.key());
+    Ok(())
+}
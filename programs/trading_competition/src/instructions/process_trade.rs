@@ -1,9 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::clock::Clock;
-use anchor_spl::token::TokenAccount;
-use anchor_lang::solana_program::program_option::COption;
 
-use crate::competition::{Competition, CompetitionError, CompetitionPhase, MockPriceAccount, Position};
+use crate::competition::{Competition, CompetitionError, CompetitionPhase, MockPriceAccount, Position, SECONDS_PER_DAY};
 use crate::events::TradeExecuted;
 
 #[derive(Accounts)]
@@ -21,11 +19,6 @@ pub struct ProcessTrade<'info> {
     )]
     pub position: Account<'info, Position>,
 
-    #[account(
-        constraint = usdc_ata.delegate == COption::Some(competition.er_instance) @ CompetitionError::AccountNotDelegated
-    )]
-    pub usdc_ata: Account<'info, TokenAccount>,
-
     pub user: Signer<'info>,
 
     /// CHECK: Verified through `competition.er_instance`
@@ -64,7 +57,7 @@ pub fn handler(
     );
 
     let current_price = mock_price.price;
-    let expo = mock_price.expo.abs() as u32;
+    let expo = mock_price.expo.unsigned_abs();
 
     // ---- Normalize price to 6 decimals (USDC) ----
     let price_norm = current_price
@@ -85,6 +78,7 @@ pub fn handler(
         .ok_or(CompetitionError::CalculationError)?;
 
     // ---- Execute trade ----
+    let prev_profit = pos.profit();
     if is_buy {
         // Buy asset → spend USDC
         pos.usdc_balance = pos.usdc_balance
@@ -103,6 +97,36 @@ pub fn handler(
             .ok_or(CompetitionError::InsufficientFunds)?;
     }
 
+    // ---- Trading statistics ----
+    if pos.trade_count == 0 {
+        pos.first_trade_at = now;
+    }
+    pos.trade_count = pos.trade_count
+        .checked_add(1)
+        .ok_or(CompetitionError::CalculationError)?;
+    if is_buy {
+        pos.buy_count = pos.buy_count
+            .checked_add(1)
+            .ok_or(CompetitionError::CalculationError)?;
+    } else {
+        pos.sell_count = pos.sell_count
+            .checked_add(1)
+            .ok_or(CompetitionError::CalculationError)?;
+    }
+    pos.total_volume = pos.total_volume
+        .checked_add(trade_value)
+        .ok_or(CompetitionError::CalculationError)?;
+    pos.last_trade_at = now;
+    let day = now / SECONDS_PER_DAY;
+    if day != pos.last_active_day {
+        pos.active_days = pos.active_days.saturating_add(1);
+        pos.last_active_day = day;
+    }
+
+    // ---- Risk tracking for scoring ----
+    pos.track_equity(now, comp.start_time)?;
+    pos.track_achievements(prev_profit);
+
     // ---- Emit event ----
     emit!(TradeExecuted {
         user: pos.user,
@@ -114,6 +138,15 @@ pub fn handler(
         new_current_value: pos.current_value,
         new_profit: pos.profit(),  // Fixed: Now works on &Position
         price_used: current_price as i64,  // Cast for event
+        trade_count: pos.trade_count,
+        buy_count: pos.buy_count,
+        sell_count: pos.sell_count,
+        total_volume: pos.total_volume,
+        peak_value: pos.peak_value,
+        max_drawdown: pos.max_drawdown,
+        first_trade_at: pos.first_trade_at,
+        last_trade_at: pos.last_trade_at,
+        achievements: pos.achievements,
     });
 
     Ok(())
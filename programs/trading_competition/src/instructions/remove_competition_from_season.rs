@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+
+#[derive(Accounts)]
+pub struct RemoveCompetitionFromSeason<'info> {
+    #[account(
+        mut,
+        constraint = !season.settled @ CompetitionError::SeasonSettled
+    )]
+    pub season: Account<'info, Season>,
+
+    /// Season owner alone may drop a competition that will never be awarded
+    #[account(address = season.authority @ CompetitionError::Unauthorized)]
+    pub season_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = competition.season == season.key() @ CompetitionError::WrongSeason,
+        // Points already in standings can't be taken back
+        constraint = competition.season_awarded == 0 @ CompetitionError::ResultsDistributed
+    )]
+    pub competition: Account<'info, Competition>,
+}
+
+pub fn handler(ctx: Context<RemoveCompetitionFromSeason>) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let comp = &mut ctx.accounts.competition;
+
+    season.release(comp)?;
+    comp.season = Pubkey::default();
+    season.competition_count = season
+        .competition_count
+        .checked_sub(1)
+        .ok_or(CompetitionError::CalculationError)?;
+
+    msg!("Competition {} left season {}", comp.key(), season.season_id);
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::WinnerChallenged;
+
+#[derive(Accounts)]
+pub struct ResolveChallenge<'info> {
+    #[account(
+        mut,
+        constraint = competition.phase == CompetitionPhase::Finalizing @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    #[account(
+        mut,
+        has_one = competition,
+        has_one = challenger,
+        seeds = [b"challenge", competition.key().as_ref(), challenger.key().as_ref()],
+        bump = challenge.bump
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        has_one = competition,
+        seeds = [b"position", competition.key().as_ref(), challenger.key().as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+
+    /// CHECK: Bond refund target – verified by has_one on `challenge`
+    #[account(mut)]
+    pub challenger: UncheckedAccount<'info>,
+
+    /// CHECK: Receives forfeited bonds
+    #[account(mut, address = competition.roles.treasurer @ CompetitionError::Unauthorized)]
+    pub treasurer: UncheckedAccount<'info>,
+}
+
+/// Permissionless – a rejected challenge forfeits its bond instead of reverting.
+pub fn handler(ctx: Context<ResolveChallenge>) -> Result<()> {
+    let comp = &mut ctx.accounts.competition;
+    let pos = &ctx.accounts.position;
+
+    let entry = pos.entry(&comp.config);
+    let previous_winner = comp.winner;
+    let previous_score = comp.winner_score;
+
+    // Judged against the head it was opened against, so resolution order
+    // can't fail a correct challenge; the committed head carries its real
+    // tie-break stats, and CoWinners also lets an equal score share.
+    let eligible = pos.verified && pos.is_eligible(&comp.config.eligibility);
+    let upheld = eligible && ctx.accounts.challenge.is_upheld_by(&entry, comp.config.tie_break);
+
+    // Any eligible challenger that makes the board is ranked, upheld or not
+    if eligible && comp.slot_of(&pos.user).is_none() {
+        comp.record(entry);
+    }
+
+    comp.open_challenges = comp
+        .open_challenges
+        .checked_sub(1)
+        .ok_or(CompetitionError::CalculationError)?;
+    let bond = ctx.accounts.challenge.bond;
+    let target = if upheld {
+        ctx.accounts.challenger.to_account_info()
+    } else {
+        ctx.accounts.treasurer.to_account_info()
+    };
+    ctx.accounts.challenge.close(target)?;
+
+    emit!(WinnerChallenged {
+        competition: comp.key(),
+        challenger: pos.user,
+        upheld,
+        previous_winner,
+        previous_score,
+        new_winner: comp.winner,
+        new_score: comp.winner_score,
+        bond,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Challenge by {} {} (score {})",
+        pos.user,
+        if upheld { "upheld" } else { "rejected – bond forfeited" },
+        entry.score
+    );
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::competition::*;
+use crate::events::TrophyRevoked;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::Field;
+use anchor_spl::token_interface::{self, Token2022, TokenMetadataUpdateField};
+
+/// Bytes the `revoked = true` entry adds to the mint's metadata.
+const REVOKED_FIELD_LEN: usize = 4 + "revoked".len() + 4 + "true".len();
+
+#[derive(Accounts)]
+pub struct RevokeSoulboundTrophy<'info> {
+    #[account(
+        constraint = moderator.key() == competition.roles.moderator @ CompetitionError::Unauthorized,
+        constraint = competition.phase == CompetitionPhase::Settled @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    #[account(
+        has_one = competition,
+        constraint = holder_position.overturned @ CompetitionError::NotOverturned,
+        seeds = [b"position", competition.key().as_ref(), holder_position.user.as_ref()],
+        bump = holder_position.bump
+    )]
+    pub holder_position: Account<'info, Position>,
+
+    /// CHECK: Token-2022 trophy mint; carries its own metadata
+    #[account(
+        mut,
+        owner = token_program.key(),
+        seeds = [b"trophy_mint", competition.key().as_ref(), holder_position.user.as_ref()],
+        bump
+    )]
+    pub trophy_mint: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Non-transferable and without a burn authority – the trophy is flagged
+/// with a `revoked` metadata field instead.
+pub fn handler(ctx: Context<RevokeSoulboundTrophy>) -> Result<()> {
+    let comp = &ctx.accounts.competition;
+    let mint = ctx.accounts.trophy_mint.to_account_info();
+
+    // ---- Top up rent for the metadata realloc ----
+    let needed = Rent::get()?
+        .minimum_balance(mint.data_len() + REVOKED_FIELD_LEN)
+        .saturating_sub(mint.lamports());
+    if needed > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.moderator.to_account_info(),
+                    to: mint.clone(),
+                },
+            ),
+            needed,
+        )?;
+    }
+
+    token_interface::token_metadata_update_field(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataUpdateField {
+                program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: mint.clone(),
+                update_authority: ctx.accounts.competition.to_account_info(),
+            },
+            &[&[b"competition", comp.authority.as_ref(), &[comp.bump]]],
+        ),
+        Field::Key("revoked".to_string()),
+        "true".to_string(),
+    )?;
+
+    emit!(TrophyRevoked {
+        holder: ctx.accounts.holder_position.user,
+        competition: comp.key(),
+        mint: mint.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::TrophyRevoked;
+use anchor_spl::token::Mint;
+use mpl_token_metadata::accounts::Metadata;
+use mpl_token_metadata::instructions::{
+    UnverifySizedCollectionItem, UpdateMetadataAccountV2, UpdateMetadataAccountV2InstructionArgs,
+};
+use mpl_token_metadata::types::DataV2;
+
+const REVOKED_TROPHY_NAME: &str = "Revoked Cypherpunk Trophy";
+
+#[derive(Accounts)]
+pub struct RevokeTrophy<'info> {
+    #[account(
+        constraint = moderator.key() == competition.roles.moderator @ CompetitionError::Unauthorized,
+        constraint = competition.phase == CompetitionPhase::Settled @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    /// Holder struck by overturn_result
+    #[account(
+        has_one = competition,
+        constraint = holder_position.overturned @ CompetitionError::NotOverturned,
+        seeds = [b"position", competition.key().as_ref(), holder_position.user.as_ref()],
+        bump = holder_position.bump
+    )]
+    pub holder_position: Account<'info, Position>,
+
+    #[account(
+        seeds = [b"nft_mint", competition.key().as_ref(), holder_position.user.as_ref()],
+        bump
+    )]
+    pub nft_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.to_bytes().as_ref(),
+            nft_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program
+    )]
+    /// CHECK: Metaplex metadata PDA
+    pub metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: PDA – signs the collection unverification
+    #[account(seeds = [b"collection_authority", competition.authority.as_ref()], bump)]
+    pub collection_authority: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"collection_mint", competition.authority.as_ref()], bump)]
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.to_bytes().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program
+    )]
+    /// CHECK: Metaplex metadata PDA of the collection
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.to_bytes().as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program
+    )]
+    /// CHECK: Metaplex master edition PDA of the collection
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+/// The NFT can't be burned without the holder, so it is flagged instead:
+/// dropped from the verified collection and renamed.
+pub fn handler(ctx: Context<RevokeTrophy>) -> Result<()> {
+    let comp = &ctx.accounts.competition;
+    let pos = &ctx.accounts.holder_position;
+
+    // ---- Leave the verified collection ----
+    let ix = UnverifySizedCollectionItem {
+        metadata: ctx.accounts.metadata_account.key(),
+        collection_authority: ctx.accounts.collection_authority.key(),
+        payer: ctx.accounts.moderator.key(),
+        collection_mint: ctx.accounts.collection_mint.key(),
+        collection: ctx.accounts.collection_metadata.key(),
+        collection_master_edition_account: ctx.accounts.collection_master_edition.key(),
+        collection_authority_record: None,
+    }
+    .instruction();
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.metadata_account.to_account_info(),
+            ctx.accounts.collection_authority.to_account_info(),
+            ctx.accounts.moderator.to_account_info(),
+            ctx.accounts.collection_mint.to_account_info(),
+            ctx.accounts.collection_metadata.to_account_info(),
+            ctx.accounts.collection_master_edition.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        ],
+        &[&[
+            b"collection_authority",
+            comp.authority.as_ref(),
+            &[ctx.bumps.collection_authority],
+        ]],
+    )?;
+
+    // ---- Rename so wallets show the revocation ----
+    let current = Metadata::safe_deserialize(&ctx.accounts.metadata_account.try_borrow_data()?)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let data = DataV2 {
+        name: REVOKED_TROPHY_NAME.to_string(),
+        symbol: current.symbol.trim_end_matches('\0').to_string(),
+        uri: current.uri.trim_end_matches('\0').to_string(),
+        seller_fee_basis_points: current.seller_fee_basis_points,
+        creators: current.creators,
+        collection: current.collection,
+        uses: current.uses,
+    };
+
+    let ix = UpdateMetadataAccountV2 {
+        metadata: ctx.accounts.metadata_account.key(),
+        update_authority: comp.key(),
+    }
+    .instruction(UpdateMetadataAccountV2InstructionArgs {
+        data: Some(data),
+        new_update_authority: None,
+        primary_sale_happened: None,
+        is_mutable: None,
+    });
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.metadata_account.to_account_info(),
+            ctx.accounts.competition.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        ],
+        &[&[b"competition", comp.authority.as_ref(), &[comp.bump]]],
+    )?;
+
+    emit!(TrophyRevoked {
+        holder: pos.user,
+        competition: comp.key(),
+        mint: ctx.accounts.nft_mint.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Trophy {} of {} revoked", ctx.accounts.nft_mint.key(), pos.user);
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+
+#[derive(Accounts)]
+pub struct SetRegistration<'info> {
+    #[account(
+        mut,
+        constraint = moderator.key() == competition.roles.moderator @ CompetitionError::Unauthorized,
+        constraint = matches!(competition.phase, CompetitionPhase::Upcoming | CompetitionPhase::Active)
+            @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    pub moderator: Signer<'info>,
+}
+
+/// Pass `0` / a zero root to lift the deadline / allowlist again.
+pub fn handler(
+    ctx: Context<SetRegistration>,
+    registration_deadline: i64,
+    allowlist_root: [u8; 32],
+) -> Result<()> {
+    let comp = &mut ctx.accounts.competition;
+
+    comp.registration_deadline = registration_deadline;
+    comp.allowlist_root = allowlist_root;
+
+    msg!(
+        "Registration for {} – deadline {}, allowlist {}",
+        comp.key(),
+        registration_deadline,
+        hex::encode(allowlist_root)
+    );
+    Ok(())
+}
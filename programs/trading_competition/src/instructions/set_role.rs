@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::RoleUpdated;
+
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    #[account(mut, has_one = authority)]
+    pub competition: Account<'info, Competition>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Hand `role` to `holder`, e.g. the oracle bot's hot key as price keeper.
+pub fn handler(ctx: Context<SetRole>, role: Role, holder: Pubkey) -> Result<()> {
+    let comp = &mut ctx.accounts.competition;
+    let slot = comp.roles.get_mut(role);
+    let previous = *slot;
+    *slot = holder;
+
+    emit!(RoleUpdated {
+        competition: comp.key(),
+        role,
+        previous,
+        holder,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
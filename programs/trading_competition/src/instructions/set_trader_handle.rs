@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+
+#[derive(Accounts)]
+pub struct SetTraderHandle<'info> {
+    #[account(
+        mut,
+        has_one = wallet,
+        seeds = [b"trader_profile", wallet.key().as_ref()],
+        bump = trader_profile.bump
+    )]
+    pub trader_profile: Account<'info, TraderProfile>,
+
+    pub wallet: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetTraderHandle>, handle: String) -> Result<()> {
+    require!(handle.len() <= MAX_HANDLE_LEN, CompetitionError::NameTooLong);
+    ctx.accounts.trader_profile.handle = handle;
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+
+#[derive(Accounts)]
+pub struct SetUriTemplate<'info> {
+    #[account(
+        mut,
+        constraint = moderator.key() == competition.roles.moderator @ CompetitionError::Unauthorized
+    )]
+    pub competition: Account<'info, Competition>,
+
+    pub moderator: Signer<'info>,
+}
+
+/// e.g. `https://host/{competition}/{winner}.json`
+pub fn handler(ctx: Context<SetUriTemplate>, uri_template: String) -> Result<()> {
+    require!(uri_template.len() <= MAX_URI_TEMPLATE_LEN, CompetitionError::UriTooLong);
+
+    // Placeholders expand to base58 keys – check the longest possible render fits
+    let widest = Pubkey::new_from_array([u8::MAX; 32]);
+    require!(
+        render_uri(&uri_template, &widest, &widest).len() <= MAX_URI_LEN,
+        CompetitionError::UriTooLong
+    );
+
+    let comp = &mut ctx.accounts.competition;
+    comp.uri_template = uri_template;
+
+    msg!("URI template for {} set to {}", comp.key(), comp.uri_template);
+    Ok(())
+}
@@ -5,21 +5,35 @@ use crate::competition::*;
 pub struct SettleCompetition<'info> {
     #[account(
         mut,
-        has_one = authority,
-        constraint = competition.phase == CompetitionPhase::Finalizing @ CompetitionError::NotActive
+        constraint = finalizer.key() == competition.roles.finalizer @ CompetitionError::Unauthorized,
+        constraint = competition.phase == CompetitionPhase::Finalizing @ CompetitionError::NotActive,
+        constraint = competition.open_challenges == 0 @ CompetitionError::ChallengesOpen,
+        constraint = competition.untallied_members == 0 @ CompetitionError::TeamsUntallied
     )]
     pub competition: Account<'info, Competition>,
-    pub authority: Signer<'info>,
+    pub finalizer: Signer<'info>,
+
+    /// Linked season – needed when the board is empty, since no points will release it
+    #[account(mut, address = competition.season @ CompetitionError::WrongSeason)]
+    pub season: Option<Account<'info, Season>>,
+
     pub clock: Sysvar<'info, Clock>,
 }
 
+/// Wins are credited per trader by the `update_rating` crank.
 pub fn handler(ctx: Context<SettleCompetition>) -> Result<()> {
     let comp = &mut ctx.accounts.competition;
-    require!(
-        Clock::get()?.unix_timestamp >= comp.challenge_deadline,
-        CompetitionError::NotEnded
-    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= comp.challenge_deadline, CompetitionError::NotEnded);
     comp.phase = CompetitionPhase::Settled;
-    msg!("Competition {} settled – NFT minting now safe", comp.key());
+    comp.review_deadline = now + REVIEW_WINDOW;
+    if comp.season != Pubkey::default() && comp.leaderboard_len == 0 {
+        let season = ctx.accounts.season.as_mut().ok_or(CompetitionError::WrongSeason)?;
+        season.release(comp)?;
+    }
+    if comp.team_leaderboard_len > 0 {
+        comp.winning_team = comp.team_leaderboard[0].team;
+    }
+    msg!("Competition {} settled – under review until {}", comp.key(), comp.review_deadline);
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::SeasonSettled;
+
+#[derive(Accounts)]
+pub struct SettleSeason<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = !season.settled @ CompetitionError::SeasonSettled,
+        constraint = season.pending_competitions == 0 @ CompetitionError::CompetitionsPending
+    )]
+    pub season: Account<'info, Season>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SettleSeason>) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    require!(season.champion != Pubkey::default(), CompetitionError::NoWinner);
+
+    season.settled = true;
+
+    emit!(SeasonSettled {
+        season: season.key(),
+        champion: season.champion,
+        champion_points: season.champion_points,
+        competitions: season.competition_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Season {} settled – champion {}", season.season_id, season.champion);
+    Ok(())
+}
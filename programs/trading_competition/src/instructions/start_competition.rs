@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::CompetitionStarted;
+
+#[derive(Accounts)]
+pub struct StartCompetition<'info> {
+    #[account(
+        mut,
+        constraint = moderator.key() == competition.roles.moderator @ CompetitionError::Unauthorized,
+        constraint = competition.phase == CompetitionPhase::Upcoming @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    pub moderator: Signer<'info>,
+}
+
+/// Opens trading. The window keeps the duration given at init but starts now.
+pub fn handler(ctx: Context<StartCompetition>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let comp = &mut ctx.accounts.competition;
+
+    let duration = comp.end_time - comp.start_time;
+    comp.start_time = now;
+    comp.end_time = now
+        .checked_add(duration)
+        .ok_or(CompetitionError::CalculationError)?;
+    comp.challenge_deadline = comp.end_time + 3600;
+    comp.phase = CompetitionPhase::Active;
+
+    emit!(CompetitionStarted {
+        competition: comp.key(),
+        participant_count: comp.participant_count,
+        start_time: comp.start_time,
+        end_time: comp.end_time,
+    });
+
+    msg!("Competition {} started – ends at {}", comp.key(), comp.end_time);
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::PositionSubmitted;
+
+#[derive(Accounts)]
+pub struct SubmitPosition<'info> {
+    #[account(
+        mut,
+        constraint = competition.phase == CompetitionPhase::Active @ CompetitionError::NotActive,
+        constraint = competition.config.finalization == FinalizationMode::OnChain
+            @ CompetitionError::WrongFinalizationMode
+    )]
+    pub competition: Account<'info, Competition>,
+
+    // Owner check on `Account` means the position is back from the ER,
+    // i.e. this is the committed state – anyone may crank it in.
+    #[account(
+        mut,
+        has_one = competition,
+        seeds = [b"position", competition.key().as_ref(), position.user.as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+}
+
+pub fn handler(ctx: Context<SubmitPosition>) -> Result<()> {
+    let comp = &mut ctx.accounts.competition;
+    let pos = &mut ctx.accounts.position;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(now >= comp.end_time, CompetitionError::NotEnded);
+    require!(now < comp.end_time + SUBMISSION_WINDOW, CompetitionError::SubmissionClosed);
+    require!(!pos.submitted, CompetitionError::AlreadySubmitted);
+
+    pos.submitted = true;
+    comp.submission_count = comp
+        .submission_count
+        .checked_add(1)
+        .ok_or(CompetitionError::CalculationError)?;
+
+    // Ineligible positions are counted but never ranked
+    let entry = pos.entry(&comp.config);
+    let eligible = pos.is_eligible(&comp.config.eligibility);
+    if eligible {
+        comp.record(entry);
+    }
+
+    emit!(PositionSubmitted {
+        user: pos.user,
+        competition: comp.key(),
+        profit: entry.profit,
+        score: entry.score,
+        eligible,
+        leader: comp.winner,
+        leader_score: comp.winner_score,
+        timestamp: now,
+    });
+
+    Ok(())
+}
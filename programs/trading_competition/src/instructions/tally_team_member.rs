@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::TeamMemberTallied;
+
+#[derive(Accounts)]
+pub struct TallyTeamMember<'info> {
+    #[account(
+        mut,
+        constraint = matches!(competition.phase, CompetitionPhase::Active | CompetitionPhase::Finalizing)
+            @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    #[account(
+        mut,
+        has_one = competition,
+        seeds = [b"team", competition.key().as_ref(), team.captain.as_ref()],
+        bump = team.bump
+    )]
+    pub team: Account<'info, Team>,
+
+    #[account(
+        has_one = team,
+        seeds = [b"team_member", competition.key().as_ref(), position.user.as_ref()],
+        bump = membership.bump
+    )]
+    pub membership: Account<'info, TeamMember>,
+
+    // Committed (undelegated) position – anyone may crank it in.
+    #[account(
+        has_one = competition,
+        seeds = [b"position", competition.key().as_ref(), position.user.as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+}
+
+/// Permissionless crank – the profit comes from the committed position, so
+/// there is nothing for a role holder to vouch for.
+pub fn handler(ctx: Context<TallyTeamMember>) -> Result<()> {
+    let comp = &mut ctx.accounts.competition;
+    let team = &mut ctx.accounts.team;
+    let pos = &ctx.accounts.position;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(now >= comp.end_time, CompetitionError::NotEnded);
+
+    let slot = team.members[..team.member_count as usize]
+        .iter()
+        .position(|m| *m == pos.user)
+        .ok_or(CompetitionError::Unauthorized)?;
+    require!(team.tallied & (1 << slot) == 0, CompetitionError::AlreadyTallied);
+
+    let profit = pos.profit();
+    team.tallied |= 1 << slot;
+    comp.untallied_members = comp
+        .untallied_members
+        .checked_sub(1)
+        .ok_or(CompetitionError::CalculationError)?;
+    team.total_profit = team
+        .total_profit
+        .checked_add(profit)
+        .ok_or(CompetitionError::CalculationError)?;
+
+    comp.record_team(team.key(), team.total_profit);
+
+    emit!(TeamMemberTallied {
+        team: team.key(),
+        competition: comp.key(),
+        member: pos.user,
+        member_profit: profit,
+        team_profit: team.total_profit,
+        timestamp: now,
+    });
+
+    Ok(())
+}
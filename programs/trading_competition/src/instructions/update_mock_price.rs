@@ -7,8 +7,8 @@ use crate::competition::{Competition, MockPriceAccount, CompetitionError, Compet
 pub struct UpdateMockPrice<'info> {
     #[account(
         mut,
-        has_one = authority,
-        seeds = [b"competition", competition.key().as_ref()],
+        constraint = price_keeper.key() == competition.roles.price_keeper @ CompetitionError::Unauthorized,
+        seeds = [b"competition", competition.authority.as_ref()],
         bump = competition.bump,
     )]
     pub competition: Account<'info, Competition>,
@@ -20,7 +20,7 @@ pub struct UpdateMockPrice<'info> {
     )]
     pub mock_price: Account<'info, MockPriceAccount>,
 
-    pub authority: Signer<'info>,
+    pub price_keeper: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<UpdateMockPrice>, new_price: u128, new_expo: i32) -> Result<()> {
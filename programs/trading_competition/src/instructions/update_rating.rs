@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::RatingUpdated;
+
+#[derive(Accounts)]
+pub struct UpdateRating<'info> {
+    #[account(
+        constraint = competition.phase == CompetitionPhase::Settled @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    #[account(
+        mut,
+        has_one = competition,
+        seeds = [b"position", competition.key().as_ref(), position.user.as_ref()],
+        bump = position.bump,
+        constraint = !position.rating_applied @ CompetitionError::AlreadyRated
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(
+        mut,
+        seeds = [b"trader_profile", position.user.as_ref()],
+        bump = trader_profile.bump
+    )]
+    pub trader_profile: Account<'info, TraderProfile>,
+}
+
+/// Permissionless crank: one call per undelegated position once the review
+/// window has closed.
+/// Also logs the result and any win on the profile – the ER cannot write it
+/// at undelegation, and the winners are only final once settled.
+pub fn handler(ctx: Context<UpdateRating>) -> Result<()> {
+    let comp = &ctx.accounts.competition;
+    let pos = &mut ctx.accounts.position;
+    let profile = &mut ctx.accounts.trader_profile;
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= comp.review_deadline, CompetitionError::UnderReview);
+    pos.rating_applied = true;
+    profile.record_result(comp.key(), pos.profit(), comp.is_winner(&pos.user), now);
+
+    let n = comp.participant_count;
+    let old_rating = profile.rating;
+    if n < 2 {
+        return Ok(());
+    }
+
+    // ---- Finishing rank (doubled); everyone off the board shares the remaining ranks ----
+    let rank_x2 = match comp.rank_of(&pos.user) {
+        Some(r) => 2 * (r as u64 + 1),
+        None => comp.leaderboard_len as u64 + 1 + n as u64,
+    };
+
+    // ---- Expected score against the field's average entry rating ----
+    let opponents = (comp.field_rating_sum.saturating_sub(pos.entry_rating as u64)
+        / (n - 1) as u64) as u32;
+    let delta = rating_delta(pos.entry_rating, opponents, rank_x2, n);
+
+    profile.rating = (old_rating as i64 + delta).max(MIN_RATING as i64) as u32;
+    profile.peak_rating = profile.peak_rating.max(profile.rating);
+    profile.rated_competitions += 1;
+
+    emit!(RatingUpdated {
+        trader: pos.user,
+        competition: comp.key(),
+        rank: rank_x2.div_ceil(2) as u32,
+        field_size: n,
+        old_rating,
+        new_rating: profile.rating,
+        timestamp: now,
+    });
+
+    Ok(())
+}
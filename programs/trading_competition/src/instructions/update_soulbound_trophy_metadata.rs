@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::competition::*;
+use crate::events::TrophyUpdated;
+use crate::instructions::mint_soulbound_trophy::{trophy_fields, TrophyFields};
+use anchor_spl::token_interface::spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use anchor_spl::token_interface::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::{Field, TokenMetadata};
+use anchor_spl::token_interface::{self, Token2022, TokenMetadataUpdateField};
+
+#[derive(Accounts)]
+pub struct UpdateSoulboundTrophyMetadata<'info> {
+    #[account(
+        constraint = moderator.key() == competition.roles.moderator @ CompetitionError::Unauthorized,
+        constraint = competition.phase == CompetitionPhase::Settled @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    #[account(
+        has_one = competition,
+        seeds = [b"position", competition.key().as_ref(), holder_position.user.as_ref()],
+        bump = holder_position.bump
+    )]
+    pub holder_position: Account<'info, Position>,
+
+    /// CHECK: Token-2022 trophy mint; carries its own metadata
+    #[account(
+        mut,
+        owner = token_program.key(),
+        seeds = [b"trophy_mint", competition.key().as_ref(), holder_position.user.as_ref()],
+        bump
+    )]
+    pub trophy_mint: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Soulbound counterpart of `update_winner_nft_metadata`: re-derive tier,
+/// name, URI, `rank` and artwork from the current standings.
+pub fn handler(ctx: Context<UpdateSoulboundTrophyMetadata>) -> Result<()> {
+    let comp = &ctx.accounts.competition;
+    let pos = &ctx.accounts.holder_position;
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let mint = ctx.accounts.trophy_mint.to_account_info();
+    let comp_key = comp.key();
+
+    // Overturned holders are off the board and go through revoke_soulbound_trophy
+    let TrophyFields { rank, metadata } = trophy_fields(comp, &comp_key, pos)?;
+    let mut updates = vec![
+        (Field::Name, metadata.name),
+        (Field::Symbol, metadata.symbol),
+        (Field::Uri, metadata.uri),
+    ];
+    updates.extend(metadata.attributes.into_iter().map(|(key, value)| (Field::Key(key), value)));
+
+    // ---- Top up rent for the metadata realloc ----
+    let new_len = {
+        let data = mint.try_borrow_data()?;
+        let state = StateWithExtensions::<MintState>::unpack(&data)?;
+        let mut metadata = state.get_variable_len_extension::<TokenMetadata>()?;
+        let old_metadata_len = metadata.tlv_size_of()?;
+        for (field, value) in &updates {
+            metadata.update(field.clone(), value.clone());
+        }
+        data.len() - old_metadata_len + metadata.tlv_size_of()?
+    };
+    let needed = Rent::get()?.minimum_balance(new_len).saturating_sub(mint.lamports());
+    if needed > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.moderator.to_account_info(),
+                    to: mint.clone(),
+                },
+            ),
+            needed,
+        )?;
+    }
+
+    for (field, value) in updates {
+        token_interface::token_metadata_update_field(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenMetadataUpdateField {
+                    program_id: token_program.clone(),
+                    metadata: mint.clone(),
+                    update_authority: ctx.accounts.competition.to_account_info(),
+                },
+                &[&[b"competition", comp.authority.as_ref(), &[comp.bump]]],
+            ),
+            field,
+            value,
+        )?;
+    }
+
+    emit!(TrophyUpdated {
+        holder: pos.user,
+        competition: comp_key,
+        mint: mint.key(),
+        rank: rank as u8 + 1,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
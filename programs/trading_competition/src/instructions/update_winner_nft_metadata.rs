@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::TrophyUpdated;
+use anchor_spl::token::Mint;
+use mpl_token_metadata::accounts::Metadata;
+use mpl_token_metadata::instructions::{UpdateMetadataAccountV2, UpdateMetadataAccountV2InstructionArgs};
+use mpl_token_metadata::types::DataV2;
+
+#[derive(Accounts)]
+pub struct UpdateWinnerNftMetadata<'info> {
+    #[account(
+        constraint = moderator.key() == competition.roles.moderator @ CompetitionError::Unauthorized,
+        constraint = competition.phase == CompetitionPhase::Settled @ CompetitionError::NotActive,
+        constraint = !competition.uri_template.is_empty() @ CompetitionError::NoUriTemplate
+    )]
+    pub competition: Account<'info, Competition>,
+
+    pub moderator: Signer<'info>,
+
+    #[account(
+        has_one = competition,
+        seeds = [b"position", competition.key().as_ref(), winner_position.user.as_ref()],
+        bump = winner_position.bump
+    )]
+    pub winner_position: Account<'info, Position>,
+
+    #[account(
+        seeds = [b"nft_mint", competition.key().as_ref(), winner_position.user.as_ref()],
+        bump
+    )]
+    pub nft_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.to_bytes().as_ref(),
+            nft_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program
+    )]
+    /// CHECK: Metaplex metadata PDA
+    pub metadata_account: UncheckedAccount<'info>,
+
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+/// Re-derive tier, name and URI from the current standings and template –
+/// holders below an overturned result move up a tier here.
+pub fn handler(ctx: Context<UpdateWinnerNftMetadata>) -> Result<()> {
+    let comp = &ctx.accounts.competition;
+    let pos = &ctx.accounts.winner_position;
+
+    // Overturned holders are off the board and go through revoke_trophy
+    let rank = comp.rank_of(&pos.user).ok_or(CompetitionError::NotWinner)?;
+    let tier = TrophyTier::from_rank(rank).ok_or(CompetitionError::NotWinner)?;
+
+    // Creators / collection / uses are carried over untouched
+    let current = Metadata::safe_deserialize(&ctx.accounts.metadata_account.try_borrow_data()?)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let data = DataV2 {
+        name: tier.name().to_string(),
+        symbol: tier.symbol().to_string(),
+        uri: render_uri(&comp.uri_template, &comp.key(), &pos.user),
+        seller_fee_basis_points: current.seller_fee_basis_points,
+        creators: current.creators,
+        collection: current.collection,
+        uses: current.uses,
+    };
+
+    let ix = UpdateMetadataAccountV2 {
+        metadata: ctx.accounts.metadata_account.key(),
+        update_authority: comp.key(),
+    }
+    .instruction(UpdateMetadataAccountV2InstructionArgs {
+        data: Some(data),
+        new_update_authority: None,
+        primary_sale_happened: None,
+        is_mutable: None,
+    });
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.metadata_account.to_account_info(),
+            ctx.accounts.competition.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        ],
+        &[&[b"competition", comp.authority.as_ref(), &[comp.bump]]],
+    )?;
+
+    emit!(TrophyUpdated {
+        holder: pos.user,
+        competition: comp.key(),
+        mint: ctx.accounts.nft_mint.key(),
+        rank: rank as u8 + 1,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
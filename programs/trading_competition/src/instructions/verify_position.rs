@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::competition::*;
+use crate::events::PositionVerified;
+use state_root::verify_proof;
+
+#[derive(Accounts)]
+pub struct VerifyPosition<'info> {
+    #[account(
+        constraint = matches!(competition.phase, CompetitionPhase::Finalizing | CompetitionPhase::Settled)
+            @ CompetitionError::NotActive
+    )]
+    pub competition: Account<'info, Competition>,
+
+    #[account(
+        mut,
+        has_one = competition,
+        seeds = [b"position", competition.key().as_ref(), position.user.as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+}
+
+pub fn handler(ctx: Context<VerifyPosition>, proof: Vec<[u8; 32]>) -> Result<()> {
+    let comp = &ctx.accounts.competition;
+    let pos = &mut ctx.accounts.position;
+
+    require!(comp.state_root != [0u8; 32], CompetitionError::NoStateRoot);
+    require!(!pos.verified, CompetitionError::AlreadyVerified);
+    require!(
+        verify_proof(&proof, &comp.state_root, pos.leaf()),
+        CompetitionError::InvalidProof
+    );
+
+    pos.verified = true;
+
+    emit!(PositionVerified {
+        user: pos.user,
+        competition: comp.key(),
+        profit: pos.profit(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Position of {} verified against state root", pos.user);
+    Ok(())
+}
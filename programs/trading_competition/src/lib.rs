@@ -3,13 +3,16 @@ use anchor_lang::prelude::*;
 pub mod competition;
 pub mod events;
 pub mod instructions;
+pub mod trophy_svg;
 
 
 use instructions::*;
-use anchor_lang::prelude::*;
+use competition::{CompetitionConfig, Role, LEADERBOARD_SIZE};
+use ephemeral_rollups_sdk::anchor::ephemeral;
 
 declare_id!("HjmkkHv5A1SPbL4zjpRJjYVj33YTTq9QYyCPkx6x6HnB");
 
+#[ephemeral]
 #[program]
 pub mod trading_competition {
     use super::*;
@@ -19,13 +22,46 @@ pub mod trading_competition {
         duration: i64,
         usdc_mint: Pubkey,
         er_instance: Pubkey,
+        config: CompetitionConfig,
     ) -> Result<()> {
-        instructions::init_competition::handler(ctx, duration, usdc_mint, er_instance)
+        instructions::init_competition::handler(ctx, duration, usdc_mint, er_instance, config)
         
     }
 
-    pub fn delegate_accounts(ctx: Context<DelegateAccounts>) -> Result<()> {
-        instructions::delegate_accounts::handler(ctx, ctx.accounts.competition.er_instance)
+    pub fn start_competition(ctx: Context<StartCompetition>) -> Result<()> {
+        instructions::start_competition::handler(ctx)
+    }
+
+    pub fn set_role(ctx: Context<SetRole>, role: Role, holder: Pubkey) -> Result<()> {
+        instructions::set_role::handler(ctx, role, holder)
+    }
+
+    pub fn set_registration(
+        ctx: Context<SetRegistration>,
+        registration_deadline: i64,
+        allowlist_root: [u8; 32],
+    ) -> Result<()> {
+        instructions::set_registration::handler(ctx, registration_deadline, allowlist_root)
+    }
+
+    pub fn delegate_accounts(
+        ctx: Context<DelegateAccounts>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let er_instance = ctx.accounts.competition.er_instance;
+        instructions::delegate_accounts::handler(ctx, er_instance, proof)
+    }
+
+    pub fn delegate_position(ctx: Context<DelegatePosition>) -> Result<()> {
+        instructions::delegate_position::handler(ctx)
+    }
+
+    pub fn create_team(ctx: Context<CreateTeam>, name: String) -> Result<()> {
+        instructions::create_team::handler(ctx, name)
+    }
+
+    pub fn join_team(ctx: Context<JoinTeam>) -> Result<()> {
+        instructions::join_team::handler(ctx)
     }
 
     pub fn process_trade(
@@ -36,20 +72,24 @@ pub mod trading_competition {
         instructions::process_trade::handler(ctx, amount, is_buy)
     }
 
-    pub fn final_commit(
-        ctx: Context<FinalCommit>,
+    pub fn final_commit<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalCommit<'info>>,
         er_instance: Pubkey,
         state_root: [u8; 32],
-        winner_pubkey: Pubkey,
-        winner_profit: i128,
     ) -> Result<()> {
-        instructions::final_commit::handler(
-            ctx,
-            er_instance,
-            state_root,
-            winner_pubkey,
-            winner_profit,
-        )
+        instructions::final_commit::handler(ctx, er_instance, state_root)
+    }
+
+    pub fn submit_position(ctx: Context<SubmitPosition>) -> Result<()> {
+        instructions::submit_position::handler(ctx)
+    }
+
+    pub fn finalize_winner(ctx: Context<FinalizeWinner>) -> Result<()> {
+        instructions::finalize_winner::handler(ctx)
+    }
+
+    pub fn tally_team_member(ctx: Context<TallyTeamMember>) -> Result<()> {
+        instructions::tally_team_member::handler(ctx)
     }
 
     pub fn user_undelegate(
@@ -59,15 +99,133 @@ pub mod trading_competition {
         instructions::user_undelegate::handler(ctx, er_instance_key)
     }
 
+    pub fn verify_position(
+        ctx: Context<VerifyPosition>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::verify_position::handler(ctx, proof)
+    }
+
+    pub fn challenge_winner(
+        ctx: Context<ChallengeWinner>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::challenge_winner::handler(ctx, proof)
+    }
+
+    pub fn resolve_challenge(ctx: Context<ResolveChallenge>) -> Result<()> {
+        instructions::resolve_challenge::handler(ctx)
+    }
+
     pub fn settle_competition(ctx: Context<SettleCompetition>) -> Result<()> {
         instructions::settle_competition::handler(ctx)
     }
 
-    pub fn mint_winner_nft(
-        ctx: Context<MintWinnerNft>,
+    pub fn fund_prize(ctx: Context<FundPrize>, amount: u64) -> Result<()> {
+        instructions::fund_prize::handler(ctx, amount)
+    }
+
+    pub fn claim_prize(ctx: Context<ClaimPrize>) -> Result<()> {
+        instructions::claim_prize::handler(ctx)
+    }
+
+    pub fn mint_winner_nft(ctx: Context<MintWinnerNft>) -> Result<()> {
+        instructions::mint_winner_nft::handler(ctx)
+    }
+
+    pub fn claim_winner_nft(ctx: Context<ClaimWinnerNft>) -> Result<()> {
+        instructions::claim_winner_nft::handler(ctx)
+    }
+
+    pub fn update_winner_nft_metadata(ctx: Context<UpdateWinnerNftMetadata>) -> Result<()> {
+        instructions::update_winner_nft_metadata::handler(ctx)
+    }
+
+    pub fn update_soulbound_trophy_metadata(ctx: Context<UpdateSoulboundTrophyMetadata>) -> Result<()> {
+        instructions::update_soulbound_trophy_metadata::handler(ctx)
+    }
+
+    pub fn revoke_trophy(ctx: Context<RevokeTrophy>) -> Result<()> {
+        instructions::revoke_trophy::handler(ctx)
+    }
+
+    pub fn overturn_result(ctx: Context<OverturnResult>) -> Result<()> {
+        instructions::overturn_result::handler(ctx)
+    }
+
+    pub fn revoke_soulbound_trophy(ctx: Context<RevokeSoulboundTrophy>) -> Result<()> {
+        instructions::revoke_soulbound_trophy::handler(ctx)
+    }
+
+    pub fn init_trophy_collection(
+        ctx: Context<InitTrophyCollection>,
         metadata_uri: String,
     ) -> Result<()> {
-        instructions::mint_winner_nft::handler(ctx, metadata_uri)
+        instructions::init_trophy_collection::handler(ctx, metadata_uri)
+    }
+
+    pub fn mint_soulbound_trophy(ctx: Context<MintSoulboundTrophy>) -> Result<()> {
+        instructions::mint_soulbound_trophy::handler(ctx)
+    }
+
+    pub fn claim_soulbound_trophy(ctx: Context<ClaimSoulboundTrophy>) -> Result<()> {
+        instructions::claim_soulbound_trophy::handler(ctx)
+    }
+
+    pub fn claim_achievement(ctx: Context<ClaimAchievement>, achievement: u8) -> Result<()> {
+        instructions::claim_achievement::handler(ctx, achievement)
+    }
+
+    pub fn init_participation_badge(ctx: Context<InitParticipationBadge>) -> Result<()> {
+        instructions::init_participation_badge::handler(ctx)
+    }
+
+    pub fn claim_participation_badge(ctx: Context<ClaimParticipationBadge>) -> Result<()> {
+        instructions::claim_participation_badge::handler(ctx)
+    }
+
+    pub fn init_season(
+        ctx: Context<InitSeason>,
+        season_id: u64,
+        points_table: [u32; LEADERBOARD_SIZE],
+    ) -> Result<()> {
+        instructions::init_season::handler(ctx, season_id, points_table)
+    }
+
+    pub fn add_competition_to_season(ctx: Context<AddCompetitionToSeason>) -> Result<()> {
+        instructions::add_competition_to_season::handler(ctx)
+    }
+
+    pub fn remove_competition_from_season(ctx: Context<RemoveCompetitionFromSeason>) -> Result<()> {
+        instructions::remove_competition_from_season::handler(ctx)
+    }
+
+    pub fn award_season_points(ctx: Context<AwardSeasonPoints>, trader: Pubkey) -> Result<()> {
+        instructions::award_season_points::handler(ctx, trader)
+    }
+
+    pub fn fund_season_prize(ctx: Context<FundSeasonPrize>, amount: u64) -> Result<()> {
+        instructions::fund_season_prize::handler(ctx, amount)
+    }
+
+    pub fn settle_season(ctx: Context<SettleSeason>) -> Result<()> {
+        instructions::settle_season::handler(ctx)
+    }
+
+    pub fn claim_season_prize(ctx: Context<ClaimSeasonPrize>) -> Result<()> {
+        instructions::claim_season_prize::handler(ctx)
+    }
+
+    pub fn update_rating(ctx: Context<UpdateRating>) -> Result<()> {
+        instructions::update_rating::handler(ctx)
+    }
+
+    pub fn set_uri_template(ctx: Context<SetUriTemplate>, uri_template: String) -> Result<()> {
+        instructions::set_uri_template::handler(ctx, uri_template)
+    }
+
+    pub fn set_trader_handle(ctx: Context<SetTraderHandle>, handle: String) -> Result<()> {
+        instructions::set_trader_handle::handler(ctx, handle)
     }
 
      pub fn update_mock_price(
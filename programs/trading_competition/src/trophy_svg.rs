@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use base64ct::{Base64, Encoding};
+use crate::competition::{CompetitionError, TrophyTier, SECONDS_PER_DAY};
+
+/// Trophy artwork rendered on-chain – no off-chain hosting needed.
+pub fn render(
+    tier: TrophyTier,
+    competition: &Pubkey,
+    rank: usize,
+    profit: i128,
+    ended_at: i64,
+) -> String {
+    let color = tier.color();
+    let comp = competition.to_string();
+    let (y, m, d) = civil_date(ended_at);
+    format!(
+        concat!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="400" height="400" viewBox="0 0 400 400" font-family="monospace">"##,
+            r##"<rect width="400" height="400" fill="#111"/>"##,
+            r##"<circle cx="200" cy="150" r="80" fill="{color}"/>"##,
+            r##"<text x="200" y="167" font-size="48" text-anchor="middle" fill="#111">#{rank}</text>"##,
+            r##"<text x="200" y="280" font-size="20" text-anchor="middle" fill="#fff">{name}</text>"##,
+            r##"<text x="200" y="315" font-size="18" text-anchor="middle" fill="{color}">{profit}</text>"##,
+            r##"<text x="200" y="345" font-size="12" text-anchor="middle" fill="#888">{head}..{tail} - {y:04}-{m:02}-{d:02}</text>"##,
+            "</svg>"
+        ),
+        color = color,
+        rank = rank + 1,
+        name = tier.name(),
+        profit = usdc(profit),
+        head = &comp[..4],
+        tail = &comp[comp.len() - 4..],
+        y = y,
+        m = m,
+        d = d,
+    )
+}
+
+/// `data:` URI with base64 payload.
+pub fn data_uri(mime: &str, body: &[u8]) -> Result<String> {
+    let mut buf = vec![0u8; Base64::encoded_len(body)];
+    let encoded = Base64::encode(body, &mut buf)
+        .map_err(|_| error!(CompetitionError::CalculationError))?;
+    Ok(format!("data:{};base64,{}", mime, encoded))
+}
+
+/// Minimal metadata JSON pointing at the embedded SVG.
+pub fn metadata_json(name: &str, symbol: &str, svg: &str) -> Result<String> {
+    Ok(format!(
+        r#"{{"name":"{}","symbol":"{}","image":"{}"}}"#,
+        name,
+        symbol,
+        data_uri("image/svg+xml", svg.as_bytes())?
+    ))
+}
+
+/// 6-dec USDC as `$1234.56` / `-$1234.56`.
+fn usdc(amount: i128) -> String {
+    let abs = amount.unsigned_abs();
+    let sign = if amount < 0 { "-" } else { "" };
+    format!("{}${}.{:02}", sign, abs / 1_000_000, (abs % 1_000_000) / 10_000)
+}
+
+/// Unix timestamp to (year, month, day), proleptic Gregorian.
+fn civil_date(ts: i64) -> (i64, u32, u32) {
+    let z = ts.div_euclid(SECONDS_PER_DAY) + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+    (y, m, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_date_handles_epoch_and_leap_years() {
+        assert_eq!(civil_date(0), (1970, 1, 1));
+        assert_eq!(civil_date(-1), (1969, 12, 31));
+        assert_eq!(civil_date(1_709_164_800), (2024, 2, 29));
+        assert_eq!(civil_date(951_782_400), (2000, 2, 29));
+        // 1900 and 2100 are not leap years
+        assert_eq!(civil_date(-2_203_891_200), (1900, 3, 1));
+        assert_eq!(civil_date(4_107_542_400 - 1), (2100, 2, 28));
+    }
+
+    #[test]
+    fn usdc_formats_sign_and_cents() {
+        assert_eq!(usdc(0), "$0.00");
+        assert_eq!(usdc(1_234_560_000), "$1234.56");
+        assert_eq!(usdc(5_000_000), "$5.00");
+        assert_eq!(usdc(-1_500_000), "-$1.50");
+        // sub-cent digits are truncated, not rounded
+        assert_eq!(usdc(1_239_999), "$1.23");
+        assert_eq!(usdc(-1_234_567_890), "-$1234.56");
+    }
+
+    #[test]
+    fn render_shows_rank_tier_profit_and_date() {
+        let comp = Pubkey::new_from_array([7; 32]);
+        let key = comp.to_string();
+        let svg = render(TrophyTier::Silver, &comp, 1, -2_500_000, 1_709_164_800);
+        assert!(svg.starts_with("<svg ") && svg.ends_with("</svg>"));
+        assert!(svg.contains(">#2<"));
+        assert!(svg.contains(TrophyTier::Silver.color()));
+        assert!(svg.contains(TrophyTier::Silver.name()));
+        assert!(svg.contains(">-$2.50<"));
+        assert!(svg.contains(&format!("{}..{} - 2024-02-29", &key[..4], &key[key.len() - 4..])));
+    }
+
+    #[test]
+    fn data_uri_is_base64() {
+        assert_eq!(data_uri("text/plain", b"hi").unwrap(), "data:text/plain;base64,aGk=");
+        let json = metadata_json("N", "S", "<svg/>").unwrap();
+        assert_eq!(
+            json,
+            r#"{"name":"N","symbol":"S","image":"data:image/svg+xml;base64,PHN2Zy8+"}"#
+        );
+    }
+}